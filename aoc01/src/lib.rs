@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use utils::{
+    error::AocError,
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input).map_err(AocError::from)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i64> {
+        part2(input)
+    }
+}
+
+/// One expense per line
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse::lines(input, |parser| parser.integer())
+}
+
+pub fn part1(expenses: &[i64]) -> Result<i64> {
+    let numbers = expenses.iter().copied().collect::<HashSet<_>>();
+
+    for number in numbers.iter() {
+        if let Some(other) = 2020i64.checked_sub(*number) {
+            if numbers.contains(&other) {
+                return product(&[*number, other]);
+            }
+        }
+    }
+
+    bail!(AocError::no_solution("no two expenses sum to 2020"));
+}
+
+pub fn part2(expenses: &[i64]) -> Result<i64> {
+    let numbers = expenses.iter().copied().collect::<HashSet<_>>();
+
+    for number in numbers.iter() {
+        for number2 in numbers.iter() {
+            for number3 in numbers.iter() {
+                let sum = number
+                    .checked_add(*number2)
+                    .and_then(|sum| sum.checked_add(*number3));
+                if sum == Some(2020) {
+                    return product(&[*number, *number2, *number3]);
+                }
+            }
+        }
+    }
+
+    bail!(AocError::no_solution("no three expenses sum to 2020"));
+}
+
+fn product(numbers: &[i64]) -> Result<i64> {
+    numbers
+        .iter()
+        .try_fold(1i64, |product, number| product.checked_mul(*number))
+        .ok_or_else(|| AocError::overflow(format!("the product of {:?}", numbers)).into())
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let err = part1(&[1, 2]).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::NoSolution(_))
+        ));
+
+        let err = part1(&[i64::MIN, -3000000000000000000, 3000000000000002020]).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::Overflow(_))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}") {
+            let _ = parse(&input);
+        }
+
+        #[test]
+        fn parse_reads_every_number(numbers in prop::collection::vec(any::<i64>(), 0..50)) {
+            let input = numbers.iter().map(|n| format!("{}\n", n)).collect::<String>();
+            prop_assert_eq!(parse(&input).unwrap(), numbers);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day::parse("12\nx\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+pub mod policy;
+
+use anyhow::Result;
+
+use utils::{error::AocError, Solution};
+
+use crate::policy::{parse, CountRange, Entry, PasswordPolicy, PositionalXor};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input).map_err(AocError::from)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        part2(input)
+    }
+}
+
+fn count_valid(entries: &[Entry], policy: &dyn PasswordPolicy) -> usize {
+    entries
+        .iter()
+        .filter(|entry| policy.is_valid(entry))
+        .count()
+}
+
+pub fn part1(entries: &[Entry]) -> Result<usize> {
+    Ok(count_valid(entries, &CountRange))
+}
+
+pub fn part2(entries: &[Entry]) -> Result<usize> {
+    Ok(count_valid(entries, &PositionalXor))
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_valid_password(line: &str) -> Result<bool> {
+        Ok(CountRange.is_valid(&parse(line)?[0]))
+    }
+
+    fn is_valid_password2(line: &str) -> Result<bool> {
+        Ok(PositionalXor.is_valid(&parse(line)?[0]))
+    }
+
+    #[test]
+    fn test_is_valid_password() {
+        assert!(is_valid_password("1-3 a: abcde").unwrap());
+        assert!(is_valid_password("2-9 c: ccccccccc").unwrap());
+        assert!(!is_valid_password("1-3 b: cdefg").unwrap());
+    }
+
+    #[test]
+    fn test_is_valid_password2() {
+        assert!(is_valid_password2("1-3 a: abcde").unwrap());
+        assert!(!is_valid_password2("1-3 b: cdefg").unwrap());
+        assert!(!is_valid_password2("2-9 c: ccccccccc").unwrap());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day::parse("1-3 a: abcde\n1-3 b\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+use utils::parse::{self, ParseError};
+
+/// One line of the input, like `1-3 a: abcde`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The line number, starting at 1
+    pub line: usize,
+    pub first: usize,
+    pub second: usize,
+    pub letter: char,
+    pub password: String,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse::lines(input, |parser| {
+        let line = parser.line();
+        let first = parser.integer()?;
+        parser.literal("-")?;
+        let second = parser.integer()?;
+        parser.literal(" ")?;
+        let letter = parser.satisfy("a letter", |ch| ch.is_ascii_alphabetic())?;
+        parser.literal(": ")?;
+        let password = parser.take_while(|_| true).to_owned();
+
+        Ok(Entry {
+            line,
+            first,
+            second,
+            letter,
+            password,
+        })
+    })
+}
+
+pub trait PasswordPolicy {
+    fn name(&self) -> &'static str;
+    fn is_valid(&self, entry: &Entry) -> bool;
+}
+
+/// The letter must occur between `first` and `second` times (part 1)
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn name(&self) -> &'static str {
+        "count range"
+    }
+
+    fn is_valid(&self, entry: &Entry) -> bool {
+        let instances = entry
+            .password
+            .chars()
+            .filter(|ch| *ch == entry.letter)
+            .count();
+
+        instances >= entry.first && instances <= entry.second
+    }
+}
+
+/// The letter must be at exactly one of the 1-based positions `first` and
+/// `second`. Positions outside the password never match.
+pub struct PositionalXor;
+
+impl PositionalXor {
+    fn matches(entry: &Entry, position: usize) -> bool {
+        position
+            .checked_sub(1)
+            .and_then(|index| entry.password.chars().nth(index))
+            == Some(entry.letter)
+    }
+}
+
+impl PasswordPolicy for PositionalXor {
+    fn name(&self) -> &'static str {
+        "positional xor"
+    }
+
+    fn is_valid(&self, entry: &Entry) -> bool {
+        Self::matches(entry, entry.first) != Self::matches(entry, entry.second)
+    }
+}
+
+/// An entry and the names of the policies it fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub entry: &'a Entry,
+    pub policies: Vec<&'static str>,
+}
+
+/// Lists every entry that fails at least one of the policies
+pub fn audit<'a>(entries: &'a [Entry], policies: &[&dyn PasswordPolicy]) -> Vec<Failure<'a>> {
+    entries
+        .iter()
+        .map(|entry| Failure {
+            entry,
+            policies: policies
+                .iter()
+                .filter(|policy| !policy.is_valid(entry))
+                .map(|policy| policy.name())
+                .collect(),
+        })
+        .filter(|failure| !failure.policies.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn entry(line: &str) -> Entry {
+        parse(line).unwrap().remove(0)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            entry("1-3 a: abcde"),
+            Entry {
+                line: 1,
+                first: 1,
+                second: 3,
+                letter: 'a',
+                password: "abcde".to_owned()
+            }
+        );
+        assert_eq!(
+            parse("1-3 a: abcde\n\n1-3 a abcde\n"),
+            Err(ParseError {
+                line: 3,
+                column: 6,
+                expected: "\": \"".to_owned(),
+                found: "' '".to_owned(),
+            })
+        );
+        assert!(parse("99999999999999999999999-3 a: abcde").is_err());
+    }
+
+    #[test]
+    fn test_positional_xor_out_of_range() {
+        assert!(!PositionalXor.is_valid(&entry("0-9 a: abc")));
+        assert!(PositionalXor.is_valid(&entry("0-1 a: abc")));
+        assert!(PositionalXor.is_valid(&entry("1-9 a: abc")));
+    }
+
+    #[test]
+    fn test_audit() {
+        let entries = parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc").unwrap();
+        let failures = audit(&entries, &[&CountRange, &PositionalXor]);
+
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].entry.line, 2);
+        assert_eq!(failures[0].policies, vec!["count range", "positional xor"]);
+        assert_eq!(failures[1].entry.line, 3);
+        assert_eq!(failures[1].policies, vec!["positional xor"]);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[0-9a-z :\\-\n]{0,100}") {
+            let _ = parse(&input);
+            let _ = parse(&noise);
+        }
+
+        #[test]
+        fn parse_accepts_entries(
+            entries in prop::collection::vec("[0-9]{1,3}-[0-9]{1,3} [a-zA-Z]: [a-z]{0,20}", 0..20)
+        ) {
+            let entries = parse(&entries.join("\n")).unwrap();
+            for entry in &entries {
+                // Neither policy may panic on positions outside the password
+                CountRange.is_valid(entry);
+                PositionalXor.is_valid(entry);
+            }
+        }
+    }
+}
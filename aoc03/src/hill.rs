@@ -0,0 +1,285 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, ensure, Error, Result};
+use utils::{error::AocError, parse::ParseError};
+
+/// Moving `right` columns and `down` rows per step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub fn new(right: usize, down: usize) -> Result<Self> {
+        ensure!(down > 0, "The slope must go down");
+        Ok(Slope { right, down })
+    }
+}
+
+/// Parses RIGHT,DOWN, like 3,1
+impl FromStr for Slope {
+    type Err = Error;
+
+    fn from_str(slope: &str) -> Result<Self> {
+        let (right, down) = slope
+            .split_once(',')
+            .ok_or_else(|| anyhow!("Expected RIGHT,DOWN, got {:?}", slope))?;
+        Slope::new(right.trim().parse()?, down.trim().parse()?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    pub slope: Slope,
+    /// Where the toboggan hits trees, as (x, y) where x keeps increasing to
+    /// the right instead of wrapping around
+    pub collisions: Vec<(usize, usize)>,
+}
+
+impl Trajectory {
+    pub fn trees(&self) -> usize {
+        self.collisions.len()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    Product,
+    Min,
+    Max,
+}
+
+impl FromStr for Aggregate {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name {
+            "product" => Ok(Aggregate::Product),
+            "min" => Ok(Aggregate::Min),
+            "max" => Ok(Aggregate::Max),
+            _ => Err(anyhow!("Unknown aggregate {:?}", name)),
+        }
+    }
+}
+
+impl Aggregate {
+    pub fn apply(self, trajectories: &[Trajectory]) -> Result<usize> {
+        let mut trees = trajectories.iter().map(Trajectory::trees);
+
+        match self {
+            Aggregate::Product => trees.try_fold(1usize, |product, trees| {
+                product
+                    .checked_mul(trees)
+                    .ok_or_else(|| AocError::overflow("the product of the trees").into())
+            }),
+            Aggregate::Min => trees.min().ok_or_else(|| anyhow!("There are no slopes")),
+            Aggregate::Max => trees.max().ok_or_else(|| anyhow!("There are no slopes")),
+        }
+    }
+}
+
+pub struct Hill {
+    data: Vec<Vec<char>>,
+}
+
+impl Hill {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let data = input
+            .split('\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        for (y, line) in data.iter().enumerate() {
+            if let Some(x) = line
+                .iter()
+                .position(|square| *square != '.' && *square != '#')
+            {
+                return Err(AocError::Parse(ParseError {
+                    line: y + 1,
+                    column: x + 1,
+                    expected: "'.' or '#'".to_owned(),
+                    found: format!("{:?}", line[x]),
+                }));
+            }
+            if line.len() != data[0].len() {
+                return Err(AocError::invalid_input(format!(
+                    "line {} has {} squares, expected {}",
+                    y + 1,
+                    line.len(),
+                    data[0].len()
+                )));
+            }
+        }
+
+        Ok(Hill { data })
+    }
+
+    /// Fails if the x of a collision does not fit in an usize. The column on
+    /// the wrapping hill is found without it, so only collisions count.
+    pub fn trajectory(&self, slope: Slope) -> Result<Trajectory, AocError> {
+        let mut collisions = vec![];
+        for (step, line) in self.data.iter().step_by(slope.down).enumerate() {
+            let width = line.len();
+            let column = (step % width) * (slope.right % width) % width;
+            if line[column] != '#' {
+                continue;
+            }
+
+            let x = step.checked_mul(slope.right).ok_or_else(|| {
+                AocError::overflow(format!(
+                    "the x of the collision at step {} going right {}",
+                    step, slope.right
+                ))
+            })?;
+            collisions.push((x, step * slope.down));
+        }
+
+        Ok(Trajectory { slope, collisions })
+    }
+
+    pub fn evaluate(&self, slopes: &[Slope]) -> Result<Vec<Trajectory>, AocError> {
+        slopes.iter().map(|slope| self.trajectory(*slope)).collect()
+    }
+
+    /// The slope with the fewest trees among every slope with `right` up to
+    /// `max_right` and `down` from 1 up to `max_down`. Ties go to the smallest slope.
+    pub fn best_slope(
+        &self,
+        max_right: usize,
+        max_down: usize,
+    ) -> Result<Option<Trajectory>, AocError> {
+        let mut best: Option<Trajectory> = None;
+        for right in 0..=max_right {
+            for down in 1..=max_down {
+                let trajectory = self.trajectory(Slope { right, down })?;
+                if best.as_ref().is_none_or(|best| {
+                    (trajectory.trees(), trajectory.slope) < (best.trees(), best.slope)
+                }) {
+                    best = Some(trajectory);
+                }
+            }
+        }
+
+        Ok(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3,1".parse::<Slope>().unwrap(), Slope { right: 3, down: 1 });
+        assert!("3,0".parse::<Slope>().is_err());
+        assert!(Hill::new("..#\n.#").is_err());
+        assert!(Hill::new("..#\n.X.").is_err());
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let hill = Hill::new(EXAMPLE).unwrap();
+        let trajectory = hill.trajectory(Slope { right: 3, down: 1 }).unwrap();
+
+        assert_eq!(trajectory.trees(), 7);
+        assert_eq!(trajectory.collisions[0], (6, 2));
+        assert_eq!(
+            hill.trajectory(Slope { right: 1, down: 2 })
+                .unwrap()
+                .trees(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let hill = Hill::new(EXAMPLE).unwrap();
+        let trajectories = hill
+            .evaluate(&[
+                Slope { right: 1, down: 1 },
+                Slope { right: 3, down: 1 },
+                Slope { right: 1, down: 2 },
+            ])
+            .unwrap();
+
+        assert_eq!(Aggregate::Product.apply(&trajectories).unwrap(), 2 * 7 * 2);
+        assert_eq!(Aggregate::Min.apply(&trajectories).unwrap(), 2);
+        assert_eq!(Aggregate::Max.apply(&trajectories).unwrap(), 7);
+        assert!(Aggregate::Max.apply(&[]).is_err());
+    }
+
+    #[test]
+    fn test_product_overflow() {
+        let trajectory = Trajectory {
+            slope: Slope { right: 1, down: 1 },
+            collisions: vec![(0, 0); 1 << 16],
+        };
+
+        assert!(Aggregate::Product.apply(&vec![trajectory; 5]).is_err());
+    }
+
+    #[test]
+    fn test_best_slope() {
+        let hill = Hill::new(EXAMPLE).unwrap();
+        let best = hill.best_slope(3, 2).unwrap().unwrap();
+        assert_eq!(best.slope, Slope { right: 0, down: 2 });
+        assert_eq!(best.trees(), 1);
+
+        let best = hill.best_slope(3, 1).unwrap().unwrap();
+        assert_eq!(best.slope, Slope { right: 2, down: 1 });
+    }
+
+    #[test]
+    fn test_steep_slopes() {
+        let slope = Slope {
+            right: usize::MAX,
+            down: 1,
+        };
+        let hill = Hill::new("..\n##\n..").unwrap();
+        assert_eq!(
+            hill.trajectory(slope).unwrap().collisions,
+            vec![(usize::MAX, 1)]
+        );
+
+        let hill = Hill::new("..\n##\n##").unwrap();
+        assert!(matches!(hill.trajectory(slope), Err(AocError::Overflow(_))));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[.#\n]{0,100}") {
+            let _ = Hill::new(&input);
+            let _ = Hill::new(&noise);
+        }
+
+        #[test]
+        fn parse_accepts_rectangles(width in 1..20usize, height in 1..20usize, seed in any::<u64>()) {
+            let input = (0..height)
+                .map(|y| {
+                    (0..width)
+                        .map(|x| if seed.rotate_left((x * 7 + y) as u32) & 1 == 1 { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let hill = Hill::new(&input).unwrap();
+            let trajectory = hill.trajectory(Slope { right: 3, down: 1 }).unwrap();
+            prop_assert!(trajectory.trees() <= height);
+        }
+    }
+}
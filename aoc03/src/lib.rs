@@ -0,0 +1,44 @@
+pub mod hill;
+
+use anyhow::Result;
+use utils::Solution;
+
+use crate::hill::{Aggregate, Hill, Slope};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Hill;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Hill> {
+        Ok(Hill::new(input)?)
+    }
+
+    fn part1(hill: &Hill) -> Result<usize> {
+        part1(hill)
+    }
+
+    fn part2(hill: &Hill) -> Result<usize> {
+        part2(hill)
+    }
+}
+
+pub fn part1(hill: &Hill) -> Result<usize> {
+    Ok(hill.trajectory(Slope { right: 3, down: 1 })?.trees())
+}
+
+pub fn part2(hill: &Hill) -> Result<usize> {
+    let slopes = [
+        Slope { right: 1, down: 1 },
+        Slope { right: 3, down: 1 },
+        Slope { right: 5, down: 1 },
+        Slope { right: 7, down: 1 },
+        Slope { right: 1, down: 2 },
+    ];
+
+    Aggregate::Product.apply(&hill.evaluate(&slopes)?)
+}
+
+utils::example_tests!();
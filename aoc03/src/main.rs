@@ -1,105 +1,62 @@
-use std::fs::read_to_string;
+use std::env;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
-fn main() -> Result<()> {
-    println!("Hello, world!");
-
-    println!("part1: {}", part1(&read_input()?)?);
-    println!("part2: {}", part2(&read_input()?)?);
-
-    Ok(())
-}
-
-fn read_input() -> Result<String> {
-    Ok(read_to_string("input.txt")?)
-}
-
-fn part1(input: &str) -> Result<usize> {
-    let hill = Hill::new(input);
-
-    Ok(hill.calc_slope(3, 1))
-}
-
-fn part2(input: &str) -> Result<usize> {
-    let hill = Hill::new(input);
-
-    Ok(hill.calc_slope(1, 1)
-        * hill.calc_slope(3, 1)
-        * hill.calc_slope(5, 1)
-        * hill.calc_slope(7, 1)
-        * hill.calc_slope(1, 2))
-}
-
-struct Hill {
-    data: Vec<Vec<char>>,
-}
+use aoc03::hill::{Aggregate, Hill, Slope};
 
-impl Hill {
-    fn new(input: &str) -> Self {
-        Hill {
-            data: input
-                .split("\n")
-                .filter(|line| !line.is_empty())
-                .map(|line| line.chars().collect::<Vec<_>>())
-                .collect::<Vec<_>>(),
+/// Usage: aoc03 [best MAX_RIGHT MAX_DOWN | slopes [--aggregate product|min|max] RIGHT,DOWN...]
+fn main() -> Result<()> {
+    let hill = Hill::new(&utils::read_input()?)?;
+    let mut args = env::args().skip(1).peekable();
+
+    match args.next().as_deref() {
+        Some("best") => {
+            let mut next_number = || -> Result<usize> {
+                Ok(args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing bound"))?
+                    .parse()?)
+            };
+            let (max_right, max_down) = (next_number()?, next_number()?);
+            match hill.best_slope(max_right, max_down)? {
+                Some(best) => println!(
+                    "right {}, down {}: {} trees",
+                    best.slope.right,
+                    best.slope.down,
+                    best.trees()
+                ),
+                None => println!("There are no slopes"),
+            }
+        }
+        Some("slopes") => {
+            let aggregate = match args.next_if(|arg| arg == "--aggregate") {
+                Some(_) => args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing aggregate"))?
+                    .parse()?,
+                None => Aggregate::Product,
+            };
+            let slopes = args
+                .map(|slope| slope.parse())
+                .collect::<Result<Vec<Slope>>>()?;
+
+            let trajectories = hill.evaluate(&slopes)?;
+            for trajectory in &trajectories {
+                println!(
+                    "right {}, down {}: {} trees at {:?}",
+                    trajectory.slope.right,
+                    trajectory.slope.down,
+                    trajectory.trees(),
+                    trajectory.collisions
+                );
+            }
+            println!("{:?}: {}", aggregate, aggregate.apply(&trajectories)?);
+        }
+        _ => {
+            println!("part1: {}", aoc03::part1(&hill)?);
+            println!("part2: {}", aoc03::part2(&hill)?);
         }
     }
 
-    fn calc_slope(&self, right: usize, down: usize) -> usize {
-        self.data
-            .iter()
-            .step_by(down)
-            .enumerate()
-            .filter(|(y, line)| {
-                let x = (y * right) % line.len();
-                line[x] == '#'
-            })
-            .count()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_name() {
-        let output = part1(
-            "..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#",
-        )
-        .unwrap();
-
-        assert_eq!(output, 7);
-    }
-
-    #[test]
-    fn test_part2() {
-        let output = part2(
-            "..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#",
-        )
-        .unwrap();
-
-        assert_eq!(output, 336);
-    }
+    Ok(())
 }
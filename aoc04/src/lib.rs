@@ -0,0 +1,220 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use maplit::hashset;
+use utils::{
+    error::AocError,
+    parse::{ParseError, Parser},
+    Solution,
+};
+
+/// The fields of a passport, by key
+pub type Passport = HashMap<String, String>;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input).map_err(AocError::from)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        part2(input)
+    }
+}
+
+pub fn part1(passports: &[Passport]) -> Result<usize> {
+    let required = hashset! {"byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"};
+
+    Ok(passports
+        .iter()
+        .filter(|passport| {
+            passport
+                .keys()
+                .map(|s| &**s)
+                .collect::<HashSet<_>>()
+                .is_superset(&required)
+        })
+        .count())
+}
+
+pub fn part2(passports: &[Passport]) -> Result<usize> {
+    Ok(passports
+        .iter()
+        .filter(|passport| is_valid_passport(passport).is_ok())
+        .count())
+}
+
+const VALID_HEIGHT: RangeInclusive<i32> = 150..=193;
+
+fn is_valid_passport(passport: &Passport) -> Result<()> {
+    let byr = passport
+        .get("byr")
+        .ok_or_else(|| anyhow!("No byr"))?
+        .parse::<i32>()?;
+
+    ensure!((1920..=2002).contains(&byr), "Invalid byr");
+
+    let iyr = passport
+        .get("iyr")
+        .ok_or_else(|| anyhow!("No iyr"))?
+        .parse::<i32>()?;
+
+    ensure!((2010..=2020).contains(&iyr), "Invalid iyr");
+
+    let eyr = passport
+        .get("eyr")
+        .ok_or_else(|| anyhow!("No eyr"))?
+        .parse::<i32>()?;
+
+    ensure!((2020..=2030).contains(&eyr), "Invalid eyr");
+
+    let hgt = passport.get("hgt").ok_or_else(|| anyhow!("No hgt"))?;
+    if hgt.ends_with("cm") {
+        let hgt = hgt[0..hgt.len() - 2].parse::<i32>()?;
+        ensure!(VALID_HEIGHT.contains(&hgt), "Invalid hgt");
+    } else if hgt.ends_with("in") {
+        let hgt = hgt[0..hgt.len() - 2].parse::<i32>()?;
+        ensure!((59..=76).contains(&hgt), "Invalid hgt");
+    } else {
+        bail!("Invalid hgt, {}", hgt);
+    }
+
+    let hcl = passport.get("hcl").ok_or_else(|| anyhow!("No hcl"))?;
+    let is_color = hcl.len() == 7
+        && hcl.starts_with('#')
+        && hcl[1..]
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || ('a'..='f').contains(&ch));
+    ensure!(is_color, "Invalid hcl");
+
+    let ecl = passport.get("ecl").ok_or_else(|| anyhow!("No ecl"))?;
+    ensure!(
+        ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&ecl.as_str()),
+        "Invalid eye color"
+    );
+
+    let pid = passport.get("pid").ok_or_else(|| anyhow!("No pid"))?;
+    ensure!(
+        pid.len() == 9 && pid.chars().all(|ch| ch.is_ascii_digit()),
+        "Invalid pid"
+    );
+
+    Ok(())
+}
+
+/// Passports are separated by blank lines, and their fields by spaces or
+/// single newlines
+pub fn parse(input: &str) -> Result<Vec<Passport>, ParseError> {
+    let mut parser = Parser::new(input);
+    let mut passports = vec![];
+
+    parser.take_while(char::is_whitespace);
+    while !parser.is_empty() {
+        let mut passport = HashMap::new();
+
+        loop {
+            let key = parser.take_while1("a key", |ch| ch != ':' && !ch.is_whitespace())?;
+            parser.literal(":")?;
+            let value = parser.take_while1("a value", |ch| !ch.is_whitespace())?;
+            passport.insert(key.to_owned(), value.to_owned());
+
+            let separator = parser.take_while(char::is_whitespace);
+            if parser.is_empty() || separator.matches('\n').count() > 1 {
+                break;
+            }
+        }
+
+        passports.push(passport);
+    }
+
+    Ok(passports)
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashmap;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn s(input: &str) -> String {
+        input.to_owned()
+    }
+
+    #[test]
+    fn test_parse() {
+        let result = parse(
+            "ecl:gry pid:860033327
+
+iyr:2013 ecl:amb
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                hashmap! {
+                    s("ecl") => s("gry"),
+                    s("pid") => s("860033327"),
+                },
+                hashmap! {
+                    s("iyr") => s("2013"),
+                    s("ecl") => s("amb"),
+                },
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[a-z0-9#: \n]{0,100}") {
+            let _ = parse(&input);
+            let _ = parse(&noise);
+        }
+
+        #[test]
+        fn parse_reads_every_passport(
+            passports in prop::collection::vec(
+                prop::collection::hash_map("[a-z]{3}", "[#a-z0-9]{1,10}", 1..8),
+                0..5
+            )
+        ) {
+            let input = passports
+                .iter()
+                .map(|passport| {
+                    passport
+                        .iter()
+                        .map(|(key, value)| format!("{}:{}", key, value))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            prop_assert_eq!(parse(&input).unwrap(), passports);
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day::parse("ecl:gry pid\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::Parse(_))
+        ));
+    }
+}
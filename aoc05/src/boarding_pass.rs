@@ -0,0 +1,249 @@
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PassError {
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+    InvalidLetter {
+        position: usize,
+        letter: char,
+        expected: &'static str,
+    },
+    SeatOutOfRange(u32),
+}
+
+impl fmt::Display for PassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PassError::WrongLength { expected, found } => write!(
+                f,
+                "Expected a boarding pass of {} letters, found {}",
+                expected, found
+            ),
+            PassError::InvalidLetter {
+                position,
+                letter,
+                expected,
+            } => write!(
+                f,
+                "Invalid letter {:?} at position {}, expected {}",
+                letter, position, expected
+            ),
+            PassError::SeatOutOfRange(seat_id) => write!(f, "There is no seat {}", seat_id),
+        }
+    }
+}
+
+impl std::error::Error for PassError {}
+
+/// The layout of the plane, given as the number of letters used for the row
+/// (F/B) and the column (L/R)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plane {
+    row_bits: u32,
+    column_bits: u32,
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Plane {
+            row_bits: 7,
+            column_bits: 3,
+        }
+    }
+}
+
+impl Plane {
+    /// Returns `None` if the seat ids would not fit in an u32
+    pub fn new(row_bits: u32, column_bits: u32) -> Option<Self> {
+        row_bits
+            .checked_add(column_bits)
+            .filter(|bits| *bits < 32)
+            .map(|_| Plane {
+                row_bits,
+                column_bits,
+            })
+    }
+
+    pub fn rows(&self) -> u32 {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> u32 {
+        1 << self.column_bits
+    }
+
+    pub fn seats(&self) -> u32 {
+        self.rows() * self.columns()
+    }
+
+    fn code_length(&self) -> usize {
+        (self.row_bits + self.column_bits) as usize
+    }
+
+    /// Decodes a pass like FBFBBFFRLR, checking the length and that the rows
+    /// only use F/B and the columns only use L/R
+    pub fn decode(&self, code: &str) -> Result<BoardingPass, PassError> {
+        let length = code.chars().count();
+        if length != self.code_length() {
+            return Err(PassError::WrongLength {
+                expected: self.code_length(),
+                found: length,
+            });
+        }
+
+        let mut seat_id = 0;
+        for (position, letter) in code.chars().enumerate() {
+            let is_row = position < self.row_bits as usize;
+            let bit = match (is_row, letter) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                (true, _) => {
+                    return Err(PassError::InvalidLetter {
+                        position,
+                        letter,
+                        expected: "F or B",
+                    })
+                }
+                (false, _) => {
+                    return Err(PassError::InvalidLetter {
+                        position,
+                        letter,
+                        expected: "L or R",
+                    })
+                }
+            };
+            seat_id = seat_id << 1 | bit;
+        }
+
+        Ok(BoardingPass {
+            seat_id,
+            plane: *self,
+        })
+    }
+
+    pub fn pass(&self, seat_id: u32) -> Result<BoardingPass, PassError> {
+        if seat_id < self.seats() {
+            Ok(BoardingPass {
+                seat_id,
+                plane: *self,
+            })
+        } else {
+            Err(PassError::SeatOutOfRange(seat_id))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardingPass {
+    seat_id: u32,
+    plane: Plane,
+}
+
+impl BoardingPass {
+    pub fn row(&self) -> u32 {
+        self.seat_id >> self.plane.column_bits
+    }
+
+    pub fn column(&self) -> u32 {
+        self.seat_id & (self.plane.columns() - 1)
+    }
+
+    pub fn seat_id(&self) -> u32 {
+        self.seat_id
+    }
+}
+
+impl FromStr for BoardingPass {
+    type Err = PassError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Plane::default().decode(code)
+    }
+}
+
+impl fmt::Display for BoardingPass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in (0..self.plane.row_bits).rev() {
+            let letter = if self.row() >> bit & 1 == 1 { 'B' } else { 'F' };
+            write!(f, "{}", letter)?;
+        }
+        for bit in (0..self.plane.column_bits).rev() {
+            let letter = if self.column() >> bit & 1 == 1 {
+                'R'
+            } else {
+                'L'
+            };
+            write!(f, "{}", letter)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        let pass = "FBFBBFFRLR".parse::<BoardingPass>().unwrap();
+
+        assert_eq!(pass.row(), 44);
+        assert_eq!(pass.column(), 5);
+        assert_eq!(pass.seat_id(), 357);
+    }
+
+    #[test]
+    fn test_display() {
+        for code in &["BFFFBBFRRR", "FFFBBBFRRR", "BBFFBBFRLL"] {
+            let pass = code.parse::<BoardingPass>().unwrap();
+            let seat_id = pass.seat_id();
+            assert_eq!(Plane::default().pass(seat_id).unwrap().to_string(), *code);
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            "FBFBBFFRL".parse::<BoardingPass>(),
+            Err(PassError::WrongLength {
+                expected: 10,
+                found: 9
+            })
+        );
+        assert_eq!(
+            "FBFBBFFRBR".parse::<BoardingPass>(),
+            Err(PassError::InvalidLetter {
+                position: 8,
+                letter: 'B',
+                expected: "L or R"
+            })
+        );
+        assert_eq!(
+            "FBFBBFLRLR".parse::<BoardingPass>(),
+            Err(PassError::InvalidLetter {
+                position: 6,
+                letter: 'L',
+                expected: "F or B"
+            })
+        );
+        assert_eq!(
+            Plane::default().pass(1024),
+            Err(PassError::SeatOutOfRange(1024))
+        );
+    }
+
+    #[test]
+    fn test_custom_plane() {
+        let plane = Plane::new(2, 2).unwrap();
+        let pass = plane.decode("BFRL").unwrap();
+
+        assert_eq!((pass.row(), pass.column(), pass.seat_id()), (2, 2, 10));
+        assert_eq!(plane.pass(10).unwrap().to_string(), "BFRL");
+        assert_eq!(Plane::new(20, 12), None);
+        assert_eq!(Plane::new(u32::MAX, 1), None);
+        assert_eq!(Plane::new(1, u32::MAX), None);
+    }
+}
@@ -0,0 +1,129 @@
+pub mod boarding_pass;
+pub mod seat_map;
+
+use anyhow::Result;
+use utils::{error::AocError, parse::ParseError, Solution};
+
+use crate::{
+    boarding_pass::{BoardingPass, PassError, Plane},
+    seat_map::SeatMap,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = SeatMap;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<SeatMap> {
+        let plane = Plane::default();
+        Ok(SeatMap::new(plane, &parse(input, plane)?))
+    }
+
+    fn part1(map: &SeatMap) -> Result<u32> {
+        part1(map)
+    }
+
+    fn part2(map: &SeatMap) -> Result<u32> {
+        part2(map)
+    }
+}
+
+pub fn parse(input: &str, plane: Plane) -> Result<Vec<BoardingPass>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            plane.decode(line).map_err(|err| {
+                let (column, expected, found) = match err {
+                    PassError::WrongLength { expected, found } => (
+                        1,
+                        format!("a boarding pass of {} letters", expected),
+                        format!("{} letters", found),
+                    ),
+                    PassError::InvalidLetter {
+                        position,
+                        letter,
+                        expected,
+                    } => (position + 1, expected.to_owned(), format!("{:?}", letter)),
+                    PassError::SeatOutOfRange(_) => {
+                        return AocError::invalid_input(err.to_string())
+                    }
+                };
+                AocError::Parse(ParseError {
+                    line: index + 1,
+                    column,
+                    expected,
+                    found,
+                })
+            })
+        })
+        .collect()
+}
+
+pub fn part1(map: &SeatMap) -> Result<u32> {
+    Ok(map.highest_seat_id().map_err(AocError::from)?)
+}
+
+pub fn part2(map: &SeatMap) -> Result<u32> {
+    Ok(map.my_seat().map_err(AocError::from)?)
+}
+
+// Before figuring out it was possible to just convert to binary:
+//
+// fn parse_seat_str(input: &str) -> Result<u32> {
+//     let mut row_min = 0;
+//     let mut row_max = 128;
+
+//     let mut col_min = 0;
+//     let mut col_max = 8;
+
+//     for chr in input.chars() {
+//         match chr {
+//             'F' => row_max = row_max - (row_max - row_min) / 2,
+//             'B' => row_min = row_min + (row_max - row_min) / 2,
+
+//             'L' => col_max = col_max - (col_max - col_min) / 2,
+//             'R' => col_min = col_min + (col_max - col_min) / 2,
+//             _ => bail!("Invalid chr, {}", chr),
+//         }
+//     }
+
+//     Ok(row_min * 8 + col_min)
+// }
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse() {
+        // assert_eq!(parse_seat_str("FBFBBFFRLR").unwrap(), 44 * 8 + 5);
+        assert_eq!(
+            parse("FBFBBFFRLR\n", Plane::default()).unwrap()[0].seat_id(),
+            44 * 8 + 5
+        );
+        assert!(parse("FBFBBFFRLR\nFBFBBFFRL1\n", Plane::default())
+            .unwrap_err()
+            .to_string()
+            .starts_with("Line 2, column 10:"));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[FBLR\n]{0,100}") {
+            let _ = parse(&input, Plane::default());
+            let _ = parse(&noise, Plane::default());
+        }
+
+        #[test]
+        fn parse_accepts_passes(passes in prop::collection::vec("[FB]{7}[LR]{3}", 0..20)) {
+            prop_assert_eq!(parse(&passes.join("\n"), Plane::default()).unwrap().len(), passes.len());
+        }
+    }
+}
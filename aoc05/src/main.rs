@@ -1,91 +1,52 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-    ops::RangeInclusive,
-};
+use std::env;
 
-use anyhow::{anyhow, bail, ensure, Result};
-use maplit::{hashmap, hashset};
-use regex::Regex;
+use anyhow::{anyhow, bail, Result};
 
-fn main() -> Result<()> {
-    println!("part1: {}", part1(&read_input()?)?);
-    println!("part2: {}", part2(&read_input()?)?);
-
-    Ok(())
-}
-
-fn read_input() -> Result<String> {
-    Ok(read_to_string("input.txt")?)
-}
-
-fn part1(input: &str) -> Result<u32> {
-    Ok(input
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .filter_map(|line| parse_seat_str2(line).ok())
-        .max()
-        .unwrap())
-}
-
-fn part2(input: &str) -> Result<u32> {
-    let used_seats = input
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .filter_map(|line| parse_seat_str2(line).ok())
-        .collect::<HashSet<_>>();
-
-    let min_seat = *used_seats.iter().min().unwrap();
-    let max_seat = *used_seats.iter().max().unwrap();
-
-    let all_seats = min_seat..=max_seat;
-    let free_seats = all_seats
-        .into_iter()
-        .filter(|seat| !used_seats.contains(seat))
-        .collect::<Vec<_>>();
-
-    Ok(free_seats[0])
-}
-
-// Before figuring out it was possible to just convert to binary:
-//
-// fn parse_seat_str(input: &str) -> Result<u32> {
-//     let mut row_min = 0;
-//     let mut row_max = 128;
-
-//     let mut col_min = 0;
-//     let mut col_max = 8;
-
-//     for chr in input.chars() {
-//         match chr {
-//             'F' => row_max = row_max - (row_max - row_min) / 2,
-//             'B' => row_min = row_min + (row_max - row_min) / 2,
-
-//             'L' => col_max = col_max - (col_max - col_min) / 2,
-//             'R' => col_min = col_min + (col_max - col_min) / 2,
-//             _ => bail!("Invalid chr, {}", chr),
-//         }
-//     }
-
-//     Ok(row_min * 8 + col_min)
-// }
+use aoc05::{boarding_pass::Plane, parse, part1, part2, seat_map::SeatMap};
 
-fn parse_seat_str2(input: &str) -> Result<u32> {
-    Ok(u32::from_str_radix(
-        &input
-            .replace(&['B', 'R'][..], "1")
-            .replace(&['F', 'L'][..], "0"),
-        2,
-    )?)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Usage: aoc05 [--plane ROW_BITS,COLUMN_BITS] [encode SEAT_ID | decode PASS | map]
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1).peekable();
+    let mut plane = Plane::default();
+
+    if args.next_if(|arg| arg == "--plane").is_some() {
+        let bits = args.next().unwrap_or_default();
+        let (row_bits, column_bits) = bits
+            .split_once(',')
+            .ok_or_else(|| anyhow!("Expected ROW_BITS,COLUMN_BITS, got {:?}", bits))?;
+        plane = Plane::new(row_bits.parse()?, column_bits.parse()?)
+            .ok_or_else(|| anyhow!("The plane {} is too large", bits))?;
+    }
 
-    #[test]
-    fn test_parse_seat_str() {
-        // assert_eq!(parse_seat_str("FBFBBFFRLR").unwrap(), 44 * 8 + 5);
-        assert_eq!(parse_seat_str2("FBFBBFFRLR").unwrap(), 44 * 8 + 5);
+    match args.next().as_deref() {
+        Some("encode") => {
+            let seat_id = args.next().ok_or_else(|| anyhow!("Missing seat id"))?;
+            println!("{}", plane.pass(seat_id.parse()?)?);
+        }
+        Some("decode") => {
+            let code = args
+                .next()
+                .ok_or_else(|| anyhow!("Missing boarding pass"))?;
+            let pass = plane.decode(&code)?;
+            println!(
+                "row {}, column {}, seat id {}",
+                pass.row(),
+                pass.column(),
+                pass.seat_id()
+            );
+        }
+        Some("map") => {
+            let map = SeatMap::new(plane, &parse(&utils::read_input()?, plane)?);
+            print!("{}", map.render());
+            println!("Free seats: {:?}", map.gaps());
+        }
+        Some(command) => bail!("Unknown command {}", command),
+        None => {
+            let map = SeatMap::new(plane, &parse(&utils::read_input()?, plane)?);
+            println!("part1: {}", part1(&map)?);
+            println!("part2: {}", part2(&map)?);
+        }
     }
+
+    Ok(())
 }
@@ -0,0 +1,268 @@
+use std::{collections::BTreeSet, fmt};
+
+use utils::error::AocError;
+
+use crate::boarding_pass::{BoardingPass, Plane};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatStatus {
+    /// Someone has a boarding pass for the seat
+    Occupied,
+    /// The seat is before the first or after the last occupied seat, so it
+    /// does not exist on this aircraft
+    Missing,
+    /// A free seat between occupied seats
+    Free,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SeatMapError {
+    NoPasses,
+    NoSeat,
+    /// More than one seat matches the rules
+    Ambiguous(Vec<u32>),
+}
+
+impl fmt::Display for SeatMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeatMapError::NoPasses => write!(f, "There are no boarding passes"),
+            SeatMapError::NoSeat => write!(f, "No free seat has occupied seats on both sides"),
+            SeatMapError::Ambiguous(seats) => write!(f, "Several seats are possible: {:?}", seats),
+        }
+    }
+}
+
+impl std::error::Error for SeatMapError {}
+
+impl From<SeatMapError> for AocError {
+    fn from(err: SeatMapError) -> Self {
+        AocError::no_solution(err.to_string())
+    }
+}
+
+/// Whole rows before the first or after the last occupied seat are not on
+/// this aircraft. Seats in the rows of the first and last occupied seat can
+/// still be missing, which [`SeatMap::status`] tells apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowStatus {
+    MissingFront,
+    MissingBack,
+    /// The row has at least one seat between the first and last occupied seat
+    Present,
+}
+
+/// The occupied seats are kept sorted, so the size of the plane does not
+/// matter until its seats are listed or drawn
+pub struct SeatMap {
+    plane: Plane,
+    occupied: BTreeSet<u32>,
+    /// The first and last occupied seat, if any
+    bounds: Option<(u32, u32)>,
+}
+
+impl SeatMap {
+    pub fn new(plane: Plane, passes: &[BoardingPass]) -> Self {
+        let occupied = passes
+            .iter()
+            .map(BoardingPass::seat_id)
+            .collect::<BTreeSet<_>>();
+        let bounds = occupied
+            .iter()
+            .next()
+            .zip(occupied.iter().next_back())
+            .map(|(first, last)| (*first, *last));
+
+        SeatMap {
+            plane,
+            occupied,
+            bounds,
+        }
+    }
+
+    pub fn highest_seat_id(&self) -> Result<u32, SeatMapError> {
+        self.bounds
+            .map(|(_, last)| last)
+            .ok_or(SeatMapError::NoPasses)
+    }
+
+    /// Seats that are not on the plane are missing
+    pub fn status(&self, seat_id: u32) -> SeatStatus {
+        if self.occupied.contains(&seat_id) {
+            return SeatStatus::Occupied;
+        }
+
+        match self.bounds {
+            Some((first, last)) if first < seat_id && seat_id < last => SeatStatus::Free,
+            _ => SeatStatus::Missing,
+        }
+    }
+
+    /// Without any passes, every row is missing from the front
+    pub fn row_status(&self, row: u32) -> RowStatus {
+        let columns = self.plane.columns();
+        match self.bounds {
+            Some((first, _)) if row < first / columns => RowStatus::MissingFront,
+            Some((_, last)) if row > last / columns => RowStatus::MissingBack,
+            Some(_) => RowStatus::Present,
+            None => RowStatus::MissingFront,
+        }
+    }
+
+    /// Every seat id without a boarding pass, including the missing ones
+    pub fn free_seats(&self) -> Vec<u32> {
+        (0..self.plane.seats())
+            .filter(|id| !self.occupied.contains(id))
+            .collect()
+    }
+
+    /// The free seats between occupied seats, found between neighbours
+    pub fn gaps(&self) -> Vec<u32> {
+        self.occupied
+            .iter()
+            .zip(self.occupied.iter().skip(1))
+            .flat_map(|(before, after)| before + 1..*after)
+            .collect()
+    }
+
+    /// Your seat is free, but the seats with the ids on either side are occupied
+    pub fn my_seat(&self) -> Result<u32, SeatMapError> {
+        if self.occupied.is_empty() {
+            return Err(SeatMapError::NoPasses);
+        }
+
+        let candidates = self
+            .occupied
+            .iter()
+            .zip(self.occupied.iter().skip(1))
+            .filter(|(before, after)| *after - *before == 2)
+            .map(|(before, _)| before + 1)
+            .collect::<Vec<_>>();
+
+        match &*candidates {
+            [] => Err(SeatMapError::NoSeat),
+            [seat] => Ok(*seat),
+            _ => Err(SeatMapError::Ambiguous(candidates)),
+        }
+    }
+
+    /// Draws one line per present row, with `#` for occupied, `.` for free
+    /// and a space for missing seats, and the aisle in the middle. The missing
+    /// rows at the front and back get a line each.
+    pub fn render(&self) -> String {
+        let rows = self.plane.rows();
+        let columns = self.plane.columns();
+        let width = (rows - 1).to_string().len();
+        let mut output = String::new();
+
+        let (front, back) = match self.bounds {
+            Some((first, last)) => (first / columns, last / columns + 1),
+            None => (rows, rows),
+        };
+        if front > 0 {
+            output.push_str(&format!("rows 0 to {} missing\n", front - 1));
+        }
+
+        for row in front..back {
+            output.push_str(&format!("{:>width$} ", row, width = width));
+            for column in 0..columns {
+                if column == columns / 2 && columns > 1 {
+                    output.push(' ');
+                }
+                output.push(match self.status(row * columns + column) {
+                    SeatStatus::Occupied => '#',
+                    SeatStatus::Free => '.',
+                    SeatStatus::Missing => ' ',
+                });
+            }
+            output.truncate(output.trim_end().len());
+            output.push('\n');
+        }
+
+        if back < rows {
+            output.push_str(&format!("rows {} to {} missing\n", back, rows - 1));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(seats: &[u32]) -> SeatMap {
+        let plane = Plane::new(2, 2).unwrap();
+        let passes = seats
+            .iter()
+            .map(|id| plane.pass(*id).unwrap())
+            .collect::<Vec<_>>();
+        SeatMap::new(plane, &passes)
+    }
+
+    #[test]
+    fn test_status() {
+        let map = map(&[2, 3, 5, 7, 8, 9]);
+
+        assert_eq!(map.status(1), SeatStatus::Missing);
+        assert_eq!(map.status(3), SeatStatus::Occupied);
+        assert_eq!(map.status(4), SeatStatus::Free);
+        assert_eq!(map.status(12), SeatStatus::Missing);
+        assert_eq!(map.status(16), SeatStatus::Missing);
+        assert_eq!(map.status(u32::MAX), SeatStatus::Missing);
+        assert_eq!(map.gaps(), vec![4, 6]);
+        assert_eq!(map.free_seats(), vec![0, 1, 4, 6, 10, 11, 12, 13, 14, 15]);
+    }
+
+    #[test]
+    fn test_my_seat() {
+        assert_eq!(map(&[2, 3, 5, 6, 7]).my_seat(), Ok(4));
+        assert_eq!(
+            map(&[2, 3, 5, 7, 8]).my_seat(),
+            Err(SeatMapError::Ambiguous(vec![4, 6]))
+        );
+        assert_eq!(map(&[2, 3, 6, 7]).my_seat(), Err(SeatMapError::NoSeat));
+        assert_eq!(map(&[]).my_seat(), Err(SeatMapError::NoPasses));
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            map(&[2, 3, 5, 6, 7, 8]).render(),
+            "0    ##
+1 .# ##
+2 #
+rows 3 to 3 missing
+"
+        );
+        assert_eq!(
+            map(&[9]).render(),
+            "rows 0 to 1 missing\n2  #\nrows 3 to 3 missing\n"
+        );
+        assert_eq!(map(&[]).render(), "rows 0 to 3 missing\n");
+    }
+
+    #[test]
+    fn test_row_status() {
+        let map = map(&[5, 7, 9]);
+
+        assert_eq!(map.row_status(0), RowStatus::MissingFront);
+        assert_eq!(map.row_status(1), RowStatus::Present);
+        assert_eq!(map.row_status(2), RowStatus::Present);
+        assert_eq!(map.row_status(3), RowStatus::MissingBack);
+    }
+
+    #[test]
+    fn test_large_plane() {
+        let plane = Plane::new(20, 11).unwrap();
+        let passes = [1_000_000, 1_000_002, 1_000_003]
+            .iter()
+            .map(|id| plane.pass(*id).unwrap())
+            .collect::<Vec<_>>();
+        let map = SeatMap::new(plane, &passes);
+
+        assert_eq!(map.gaps(), vec![1_000_001]);
+        assert_eq!(map.my_seat(), Ok(1_000_001));
+        assert_eq!(map.render().lines().count(), 3);
+    }
+}
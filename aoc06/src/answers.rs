@@ -0,0 +1,266 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    ops::{BitAnd, BitOr, Sub},
+};
+
+use utils::{error::AocError, parse::ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidAnswer {
+    /// The line number, starting at 1
+    pub line: usize,
+    /// Starting at 1, counted in characters
+    pub column: usize,
+    pub character: char,
+}
+
+impl fmt::Display for InvalidAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: {:?} is not a question, expected a-z",
+            self.line, self.column, self.character
+        )
+    }
+}
+
+impl std::error::Error for InvalidAnswer {}
+
+impl From<InvalidAnswer> for AocError {
+    fn from(err: InvalidAnswer) -> Self {
+        AocError::Parse(ParseError {
+            line: err.line,
+            column: err.column,
+            expected: "a question from a to z".to_owned(),
+            found: format!("{:?}", err.character),
+        })
+    }
+}
+
+/// The questions from a to z someone answered yes to, as one bit per question
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AnswerSet(u32);
+
+impl AnswerSet {
+    /// The identity for union
+    pub const EMPTY: Self = AnswerSet(0);
+    /// The identity for intersection
+    pub const ALL: Self = AnswerSet((1 << 26) - 1);
+
+    /// Returns the first character outside a-z as the error
+    pub fn parse(answers: &str) -> Result<Self, char> {
+        answers.chars().try_fold(Self::EMPTY, |set, question| {
+            Self::bit(question).map(|bit| AnswerSet(set.0 | bit))
+        })
+    }
+
+    fn bit(question: char) -> Result<u32, char> {
+        if question.is_ascii_lowercase() {
+            Ok(1 << (question as u32 - 'a' as u32))
+        } else {
+            Err(question)
+        }
+    }
+
+    pub fn contains(&self, question: char) -> bool {
+        Self::bit(question).is_ok_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        AnswerSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        AnswerSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: Self) -> Self {
+        AnswerSet(self.0 & !other.0)
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = char> {
+        ('a'..='z').filter(move |question| self.contains(*question))
+    }
+}
+
+impl BitOr for AnswerSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for AnswerSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(rhs)
+    }
+}
+
+impl Sub for AnswerSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.difference(rhs)
+    }
+}
+
+/// The answers of everyone in one group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub people: Vec<AnswerSet>,
+}
+
+impl Group {
+    /// The questions anyone in the group answered yes to
+    pub fn anyone(&self) -> AnswerSet {
+        self.people.iter().fold(AnswerSet::EMPTY, |a, b| a | *b)
+    }
+
+    /// The questions everyone in the group answered yes to. For a group
+    /// without people, this is every question.
+    pub fn everyone(&self) -> AnswerSet {
+        self.people.iter().fold(AnswerSet::ALL, |a, b| a & *b)
+    }
+
+    /// The questions exactly `k` people in the group answered yes to
+    pub fn exactly(&self, k: usize) -> AnswerSet {
+        AnswerSet(
+            (0..26)
+                .filter(|bit| self.count(1 << bit) == k)
+                .fold(0, |set, bit| set | 1 << bit),
+        )
+    }
+
+    /// How many people answered yes to each question anyone answered yes to
+    pub fn histogram(&self) -> BTreeMap<char, usize> {
+        self.anyone()
+            .iter()
+            .map(|question| {
+                let count = self
+                    .people
+                    .iter()
+                    .filter(|person| person.contains(question))
+                    .count();
+                (question, count)
+            })
+            .collect()
+    }
+
+    fn count(&self, bit: u32) -> usize {
+        self.people
+            .iter()
+            .filter(|person| person.0 & bit != 0)
+            .count()
+    }
+}
+
+/// Groups are separated by blank lines, and each line is one person
+pub fn parse(input: &str) -> Result<Vec<Group>, InvalidAnswer> {
+    let mut groups = vec![];
+    let mut people = vec![];
+
+    for (index, raw) in input.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() {
+            if !people.is_empty() {
+                groups.push(Group { people });
+                people = vec![];
+            }
+            continue;
+        }
+
+        people.push(AnswerSet::parse(line).map_err(|character| InvalidAnswer {
+            line: index + 1,
+            column: raw.chars().position(|ch| ch == character).unwrap_or(0) + 1,
+            character,
+        })?);
+    }
+
+    if !people.is_empty() {
+        groups.push(Group { people });
+    }
+
+    Ok(groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn set(answers: &str) -> AnswerSet {
+        AnswerSet::parse(answers).unwrap()
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        assert_eq!(set("abc") | set("cd"), set("abcd"));
+        assert_eq!(set("abc") & set("cd"), set("c"));
+        assert_eq!(set("abc") - set("cd"), set("ab"));
+        assert_eq!(set("abc") | AnswerSet::EMPTY, set("abc"));
+        assert_eq!(set("abc") & AnswerSet::ALL, set("abc"));
+        assert_eq!(AnswerSet::ALL.len(), 26);
+        assert_eq!(set("zza").iter().collect::<String>(), "az");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(AnswerSet::parse("abC"), Err('C'));
+        assert_eq!(
+            parse("abc\n\na\n xæ\n"),
+            Err(InvalidAnswer {
+                line: 4,
+                column: 3,
+                character: 'æ'
+            })
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let groups = parse("ab\nac\nabd\n\n\nz\n").unwrap();
+        assert_eq!(groups.len(), 2);
+
+        let group = &groups[0];
+        assert_eq!(group.anyone(), set("abcd"));
+        assert_eq!(group.everyone(), set("a"));
+        assert_eq!(group.exactly(2), set("b"));
+        assert_eq!(group.exactly(1), set("cd"));
+        assert_eq!(
+            group.histogram().into_iter().collect::<Vec<_>>(),
+            vec![('a', 3), ('b', 2), ('c', 1), ('d', 1)]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[a-z\n]{0,100}") {
+            let _ = parse(&input);
+            let _ = parse(&noise);
+        }
+
+        #[test]
+        fn parse_reads_every_group(
+            groups in prop::collection::vec(prop::collection::vec("[a-z]{1,26}", 1..5), 0..5)
+        ) {
+            let input = groups
+                .iter()
+                .map(|people| people.join("\n"))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            prop_assert_eq!(parse(&input).unwrap().len(), groups.len());
+        }
+    }
+}
@@ -0,0 +1,50 @@
+pub mod answers;
+
+use anyhow::Result;
+use utils::{error::AocError, Solution};
+
+use crate::answers::{parse, Group};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Group>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input).map_err(AocError::from)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        part2(input)
+    }
+}
+
+pub fn part1(groups: &[Group]) -> Result<usize> {
+    Ok(groups.iter().map(|group| group.anyone().len()).sum())
+}
+
+pub fn part2(groups: &[Group]) -> Result<usize> {
+    Ok(groups.iter().map(|group| group.everyone().len()).sum())
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day::parse("abc\nx1\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::Parse(_))
+        ));
+    }
+}
@@ -1,113 +1,41 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-    ops::RangeInclusive,
-};
+use std::{collections::BTreeMap, env};
 
-use anyhow::Result;
-use maplit::hashset;
+use anyhow::{anyhow, Result};
 
-fn main() -> Result<()> {
-    println!("part1: {}", part1(&read_input()?)?);
-    println!("part2: {}", part2(&read_input()?)?);
-
-    Ok(())
-}
-
-fn read_input() -> Result<String> {
-    Ok(read_to_string("input.txt")?)
-}
-
-fn part1(input: &str) -> Result<usize> {
-    Ok(input
-        .split("\n\n")
-        .map(|group| {
-            group
-                .split('\n')
-                .map(|answers| answers.chars().collect::<HashSet<_>>())
-                .fold(HashSet::new(), |a, b| {
-                    a.union(&b).copied().collect::<HashSet<_>>()
-                })
-                .len()
-        })
-        .sum())
-}
-
-fn part2(input: &str) -> Result<usize> {
-    Ok((input
-        .split("\n\n")
-        .filter(|a| !a.is_empty())
-        .map(|group| {
-            group
-                .split('\n')
-                .filter(|a| !a.is_empty())
-                .map(|answers| answers.chars().collect::<HashSet<_>>())
-                .fold(hashset! {'æ'}, |acc, x| {
-                    if acc.contains(&'æ') && acc.len() == 1 {
-                        // For the first element, just return
-                        x
-                    } else {
-                        acc.intersection(&x).copied().collect::<HashSet<_>>()
-                    }
-                })
-                .len()
-        })
-        .collect::<Vec<_>>())
-    .iter()
-    .sum())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let output = part1(
-            "abc
+use aoc06::answers::parse;
 
-a
-b
-c
-
-ab
-ac
-
-a
-a
-a
-a
-
-b
-",
-        )
-        .unwrap();
-
-        assert_eq!(output, 11);
+/// Usage: aoc06 [exactly K | histogram]
+fn main() -> Result<()> {
+    let groups = parse(&utils::read_input()?)?;
+    let mut args = env::args().skip(1);
+
+    match args.next().as_deref() {
+        // The sum over the groups of questions exactly K people answered yes to
+        Some("exactly") => {
+            let k = args.next().ok_or_else(|| anyhow!("Missing K"))?.parse()?;
+            let sum = groups
+                .iter()
+                .map(|group| group.exactly(k).len())
+                .sum::<usize>();
+            println!("{}", sum);
+        }
+        // How many people answered yes to each question in total
+        Some("histogram") => {
+            let mut totals = BTreeMap::new();
+            for group in &groups {
+                for (question, count) in group.histogram() {
+                    *totals.entry(question).or_insert(0) += count;
+                }
+            }
+            for (question, count) in totals {
+                println!("{}: {}", question, count);
+            }
+        }
+        _ => {
+            println!("part1: {}", aoc06::part1(&groups)?);
+            println!("part2: {}", aoc06::part2(&groups)?);
+        }
     }
 
-    #[test]
-    fn test_part2() {
-        let output = part2(
-            "abc
-
-a
-b
-c
-
-ab
-ac
-
-a
-a
-a
-a
-
-b
-",
-        )
-        .unwrap();
-
-        assert_eq!(output, 6);
-    }
+    Ok(())
 }
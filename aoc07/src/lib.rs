@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use utils::{
+    error::AocError,
+    graph::{Graph, NodeId},
+    memo::Memo,
+    parse::{self, ParseError, Parser},
+    Solution,
+};
+
+/// The bags directly inside each bag, with how many of them there are
+pub type Rules = HashMap<String, HashMap<String, u32>>;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Rules;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Rules> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(bags: &Rules) -> Result<usize> {
+        part1(bags)
+    }
+
+    fn part2(bags: &Rules) -> Result<u32> {
+        part2(bags)
+    }
+}
+
+/// An edge from every bag to each bag directly inside it, weighted by how
+/// many of them there are
+fn graph(bags: &Rules) -> Graph<&str, u32> {
+    let mut graph = Graph::new();
+    for (outer, content) in bags {
+        graph.add_node(outer.as_str());
+        for (inner, count) in content {
+            graph.add_edge(outer.as_str(), inner.as_str(), *count);
+        }
+    }
+
+    graph
+}
+
+pub fn part1(bags: &Rules) -> Result<usize> {
+    let contained_in = graph(bags).reversed();
+
+    Ok(match contained_in.id("shiny gold") {
+        Some(gold) => contained_in.reachable(gold).len() - 1,
+        None => 0,
+    })
+}
+
+pub fn part2(bags: &Rules) -> Result<u32> {
+    let graph = graph(bags);
+    let gold = graph
+        .id("shiny gold")
+        .ok_or_else(|| AocError::invalid_input("there is no rule for shiny gold bags"))?;
+
+    let count = |memo: &mut Memo<NodeId, u32, AocError>, id: NodeId| {
+        get_number_of_bags_in_the_bag(memo, &graph, bags, id)
+    };
+    let on_cycle = |cycle: Vec<NodeId>| {
+        let names = cycle.iter().map(|id| *graph.node(*id)).collect::<Vec<_>>();
+        AocError::invalid_input(format!(
+            "{} bags end up inside themselves: {}",
+            names[0],
+            names.join(" -> ")
+        ))
+    };
+    let mut memo = Memo::new(&count).detect_cycles(&on_cycle);
+
+    Ok(memo.get(gold)? - 1)
+}
+
+/// Counts the bag itself too, looking up the bags inside it through `memo`
+fn get_number_of_bags_in_the_bag(
+    memo: &mut Memo<NodeId, u32, AocError>,
+    graph: &Graph<&str, u32>,
+    bags: &Rules,
+    id: NodeId,
+) -> Result<u32, AocError> {
+    let name = *graph.node(id);
+    if !bags.contains_key(name) {
+        return Err(AocError::invalid_input(format!(
+            "there is no rule for {} bags",
+            name
+        )));
+    }
+
+    let mut answer = 1u32;
+    for (inner, count) in graph.edges(id) {
+        let bags_inside = memo.get(*inner)?;
+        answer = count
+            .checked_mul(bags_inside)
+            .and_then(|bags_inside| answer.checked_add(bags_inside))
+            .ok_or_else(|| AocError::overflow(format!("the number of bags in {} bags", name)))?;
+    }
+
+    Ok(answer)
+}
+
+/// Like "light red bags contain 1 bright white bag, 2 muted yellow bags."
+pub fn parse(input: &str) -> Result<Rules, ParseError> {
+    let rules = parse::lines(input, |parser| {
+        let name = bag(parser)?;
+        parser.literal(" contain ")?;
+
+        let content = if parser.try_literal("no other bags") {
+            HashMap::new()
+        } else {
+            parser
+                .separated(", ", |parser| {
+                    let count = parser.integer()?;
+                    parser.literal(" ")?;
+                    Ok((bag(parser)?, count))
+                })?
+                .into_iter()
+                .collect()
+        };
+        parser.literal(".")?;
+
+        Ok((name, content))
+    })?;
+
+    Ok(rules.into_iter().collect())
+}
+
+/// Like "bright white bag" or "muted yellow bags"
+fn bag(parser: &mut Parser) -> Result<String, ParseError> {
+    let adjective = parser.word()?;
+    parser.literal(" ")?;
+    let color = parser.word()?;
+    parser.literal(" bag")?;
+    parser.try_literal("s");
+
+    Ok(format!("{} {}", adjective, color))
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use maplit::hashmap;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn s(s: &str) -> String {
+        s.to_owned()
+    }
+
+    #[test]
+    fn test_parse() {
+        let output = parse(
+            "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+faded blue bags contain no other bags.
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            hashmap! {
+                s("light red") => hashmap![s("bright white") => 1, s("muted yellow") => 2],
+                s("dark orange") => hashmap![s("bright white") => 3, s("muted yellow") => 4],
+                s("faded blue") => hashmap![],
+            }
+        )
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = parse(
+            "light red bags contain 1 bright white bag.
+dark orange bags hold 3 bright white bags.
+",
+        )
+        .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 17));
+        assert_eq!(error.expected, "\" contain \"");
+    }
+
+    #[test]
+    fn test_part2_errors() {
+        let error = |input| match part2(&parse(input).unwrap())
+            .unwrap_err()
+            .downcast::<AocError>()
+        {
+            Ok(error) => error,
+            Err(error) => panic!("not an AocError: {}", error),
+        };
+
+        assert_eq!(
+            error("shiny gold bags contain 1 dark red bag.\n"),
+            AocError::invalid_input("there is no rule for dark red bags")
+        );
+        assert_eq!(
+            error(
+                "shiny gold bags contain 1 dark red bag.
+dark red bags contain 2 shiny gold bags.
+"
+            ),
+            AocError::invalid_input(
+                "shiny gold bags end up inside themselves: shiny gold -> dark red -> shiny gold"
+            )
+        );
+        assert!(matches!(
+            error(
+                "shiny gold bags contain 70000 dark red bags.
+dark red bags contain 70000 faded blue bags.
+faded blue bags contain no other bags.
+"
+            ),
+            AocError::Overflow(_)
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[a-z0-9 ,.\n]{0,100}") {
+            let _ = parse(&input);
+            let _ = parse(&noise);
+        }
+
+        #[test]
+        fn parse_reads_every_rule(
+            rules in prop::collection::hash_map(
+                "[a-z]{1,8} [a-z]{1,8}",
+                prop::collection::hash_map("[a-z]{1,8} [a-z]{1,8}", 1..10u32, 0..4),
+                0..10
+            )
+        ) {
+            let input = rules
+                .iter()
+                .map(|(name, content)| {
+                    let content = if content.is_empty() {
+                        "no other bags".to_owned()
+                    } else {
+                        content
+                            .iter()
+                            .map(|(name, count)| format!("{} {} bags", count, name))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    };
+                    format!("{} bags contain {}.\n", name, content)
+                })
+                .collect::<String>();
+            prop_assert_eq!(parse(&input).unwrap(), rules);
+        }
+    }
+}
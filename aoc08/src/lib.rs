@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use utils::{
+    error::AocError,
+    parse::{self, ParseError},
+    Solution,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<OpCode>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input).map_err(AocError::from)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        part2(input)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum OpCode {
+    Nop(i32),
+    Acc(i32),
+    Jmp(i32),
+}
+
+/// One instruction per line, like "jmp -3"
+pub fn parse(input: &str) -> Result<Vec<OpCode>, ParseError> {
+    parse::lines(input, |parser| {
+        let start = parser.clone();
+        let operation = parser.word()?;
+        parser.literal(" ")?;
+        let value = parser.integer()?;
+
+        match operation {
+            "nop" => Ok(OpCode::Nop(value)),
+            "acc" => Ok(OpCode::Acc(value)),
+            "jmp" => Ok(OpCode::Jmp(value)),
+            _ => Err(start.error("nop, acc or jmp")),
+        }
+    })
+}
+
+pub fn part1(instructions: &[OpCode]) -> Result<i32> {
+    match run(instructions)? {
+        Exit::Looped(acc) => Ok(acc),
+        Exit::Terminated(_) => bail!(AocError::no_solution("the program never loops")),
+        Exit::JumpedOut(pc) => bail!(AocError::invalid_input(format!(
+            "instruction {} jumps outside the program",
+            pc + 1
+        ))),
+    }
+}
+
+/// How a program stops
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exit {
+    /// Right after the last instruction, with the accumulator
+    Terminated(i32),
+    /// Just before an instruction would run a second time
+    Looped(i32),
+    /// The instruction at this index jumped outside the code, or before the
+    /// start
+    JumpedOut(usize),
+}
+
+fn run(code: &[OpCode]) -> Result<Exit, AocError> {
+    let mut acc = 0i32;
+    let mut pc = 0;
+    let mut visited = HashSet::new();
+
+    loop {
+        if pc == code.len() {
+            return Ok(Exit::Terminated(acc));
+        }
+        if !visited.insert(pc) {
+            return Ok(Exit::Looped(acc));
+        }
+        let next = match code[pc] {
+            OpCode::Nop(_) => pc.checked_add(1),
+            OpCode::Acc(value) => {
+                acc = acc
+                    .checked_add(value)
+                    .ok_or_else(|| AocError::overflow("the accumulator"))?;
+                pc.checked_add(1)
+            }
+            OpCode::Jmp(value) => pc.checked_add_signed(value as isize),
+        };
+        pc = match next {
+            Some(next) if next <= code.len() => next,
+            _ => return Ok(Exit::JumpedOut(pc)),
+        };
+    }
+}
+
+pub fn part2(instructions: &[OpCode]) -> Result<i32> {
+    for (i, op) in instructions.iter().enumerate() {
+        let mut code = instructions.to_vec();
+        code[i] = match op {
+            OpCode::Acc(_) => continue,
+            OpCode::Nop(val) => OpCode::Jmp(*val),
+            OpCode::Jmp(val) => OpCode::Nop(*val),
+        };
+        if let Exit::Terminated(acc) = run(&code)? {
+            return Ok(acc);
+        }
+    }
+
+    bail!(AocError::no_solution(
+        "swapping a single jmp or nop never makes the program terminate"
+    ))
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse("nop +0\nmul +3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, "\"mul\"");
+
+        let err = Day::parse("nop +0\nmul +3\n").unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<AocError>(),
+            Some(&AocError::Parse(error))
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let code = |input| parse(input).unwrap();
+
+        assert_eq!(run(&code("acc +1\njmp -1\n")), Ok(Exit::Looped(1)));
+        assert_eq!(run(&code("acc +1\njmp +1\n")), Ok(Exit::Terminated(1)));
+        assert_eq!(run(&code("nop +0\njmp -2\n")), Ok(Exit::JumpedOut(1)));
+        assert_eq!(run(&code("jmp +3\nnop +0\n")), Ok(Exit::JumpedOut(0)));
+        assert_eq!(
+            run(&code("acc +2147483647\nacc +1\n")),
+            Err(AocError::overflow("the accumulator"))
+        );
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let error = part2(&parse("jmp +0\njmp -1\n").unwrap()).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<AocError>(),
+            Some(AocError::NoSolution(_))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[nopacjm +\\-0-9\n]{0,100}") {
+            let _ = parse(&input);
+            let _ = parse(&noise);
+        }
+
+        #[test]
+        fn parse_accepts_programs(
+            program in prop::collection::vec(("nop|acc|jmp", any::<i32>()), 0..20)
+        ) {
+            let input = program
+                .iter()
+                .map(|(operation, value)| format!("{} {:+}\n", operation, value))
+                .collect::<String>();
+            prop_assert_eq!(parse(&input).unwrap().len(), program.len());
+        }
+
+        #[test]
+        fn parts_never_panic(
+            program in prop::collection::vec(("nop|acc|jmp", -5..5i32), 0..20)
+        ) {
+            let input = program
+                .iter()
+                .map(|(operation, value)| format!("{} {:+}\n", operation, value))
+                .collect::<String>();
+            let instructions = parse(&input).unwrap();
+            let _ = part1(&instructions);
+            let _ = part2(&instructions);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+pub mod xmas;
+
+use anyhow::{bail, Result};
+use utils::{error::AocError, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(xmas::parse(input).map_err(AocError::from)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i64> {
+        part2(input)
+    }
+}
+
+pub fn part1(numbers: &[i64]) -> Result<i64> {
+    solve_part1(numbers, 25)
+}
+
+fn solve_part1(numbers: &[i64], preamble: usize) -> Result<i64> {
+    Ok(first_invalid(numbers, preamble)?)
+}
+
+fn first_invalid(numbers: &[i64], preamble: usize) -> Result<i64, AocError> {
+    xmas::events(numbers, preamble)
+        .find(xmas::Event::is_invalid)
+        .map(|event| event.number())
+        .ok_or_else(|| AocError::no_solution("all numbers are valid"))
+}
+
+pub fn part2(numbers: &[i64]) -> Result<i64> {
+    solve_part2(numbers, 25)
+}
+
+/// The sum of the smallest and largest number in the first run of at least
+/// two numbers that add up to the invalid number
+fn solve_part2(numbers: &[i64], preamble: usize) -> Result<i64> {
+    let invalid_number = first_invalid(numbers, preamble)?;
+
+    for start in 0..numbers.len() {
+        let mut sum = numbers[start];
+        for end in start + 1..numbers.len() {
+            sum = match sum.checked_add(numbers[end]) {
+                Some(sum) => sum,
+                None => break,
+            };
+            if sum == invalid_number {
+                let range = &numbers[start..=end];
+                let max = range.iter().max().unwrap_or(&0);
+                let min = range.iter().min().unwrap_or(&0);
+                return max.checked_add(*min).ok_or_else(|| {
+                    AocError::overflow("the smallest plus the largest number").into()
+                });
+            }
+        }
+    }
+
+    bail!(AocError::no_solution(format!(
+        "no run of numbers adds up to {}",
+        invalid_number
+    )))
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_part1() {
+        assert_eq!(
+            solve_part1(
+                &xmas::parse(
+                    "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+"
+                )
+                .unwrap(),
+                5
+            )
+            .unwrap(),
+            127
+        )
+    }
+
+    #[test]
+    fn test_solve_part2() {
+        assert_eq!(
+            solve_part2(
+                &xmas::parse(
+                    "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+"
+                )
+                .unwrap(),
+                5
+            )
+            .unwrap(),
+            62
+        )
+    }
+
+    #[test]
+    fn test_no_solution() {
+        let error = solve_part2(&[1, 2, 3, 5, 8], 2).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<AocError>(),
+            Some(&AocError::no_solution("all numbers are valid"))
+        );
+
+        let error = solve_part2(&[1, 2, 3, 100], 2).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<AocError>(),
+            Some(&AocError::no_solution("no run of numbers adds up to 100"))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day::parse("1\nx\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    collections::VecDeque,
+    io::{BufRead, Lines},
+};
+
+use anyhow::Result;
+use utils::{
+    error::AocError,
+    parse::{self, ParseError, Parser},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// One of the first `preamble` numbers, which are accepted without checking
+    Preamble { position: usize, number: i64 },
+    /// The number is the sum of two different numbers in the window before it
+    Valid { position: usize, number: i64 },
+    /// The number is not the sum of any two different numbers in the window before it
+    Invalid { position: usize, number: i64 },
+}
+
+impl Event {
+    pub fn position(&self) -> usize {
+        match *self {
+            Event::Preamble { position, .. }
+            | Event::Valid { position, .. }
+            | Event::Invalid { position, .. } => position,
+        }
+    }
+
+    pub fn number(&self) -> i64 {
+        match *self {
+            Event::Preamble { number, .. }
+            | Event::Valid { number, .. }
+            | Event::Invalid { number, .. } => number,
+        }
+    }
+
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Event::Invalid { .. })
+    }
+}
+
+/// One number per line, with blank lines skipped
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse::lines(input, |parser| {
+        parser.spaces();
+        let number = parser.integer()?;
+        parser.take_while(char::is_whitespace);
+        Ok(number)
+    })
+}
+
+/// The last `preamble` numbers, which the next number is checked against
+struct Window {
+    preamble: usize,
+    window: VecDeque<i64>,
+    position: usize,
+}
+
+impl Window {
+    fn new(preamble: usize) -> Self {
+        Window {
+            preamble,
+            window: VecDeque::with_capacity(preamble + 1),
+            position: 0,
+        }
+    }
+
+    fn is_sum_of_two(&self, number: i64) -> bool {
+        self.window.iter().enumerate().any(|(i, a)| {
+            self.window
+                .iter()
+                .skip(i + 1)
+                .any(|b| a != b && a.checked_add(*b) == Some(number))
+        })
+    }
+
+    fn check(&mut self, number: i64) -> Event {
+        let position = self.position;
+        self.position += 1;
+
+        let event = if self.window.len() < self.preamble {
+            Event::Preamble { position, number }
+        } else if self.is_sum_of_two(number) {
+            Event::Valid { position, number }
+        } else {
+            Event::Invalid { position, number }
+        };
+
+        self.window.push_back(number);
+        if self.window.len() > self.preamble {
+            self.window.pop_front();
+        }
+
+        event
+    }
+}
+
+/// Checks each number against the `preamble` numbers before it
+pub fn events(numbers: &[i64], preamble: usize) -> impl Iterator<Item = Event> + '_ {
+    let mut window = Window::new(preamble);
+    numbers.iter().map(move |number| window.check(*number))
+}
+
+/// Reads numbers one line at a time and checks each one against the `preamble`
+/// numbers before it. Only the window is kept in memory, so the input can be
+/// arbitrarily large.
+pub struct Validator<R> {
+    lines: Lines<R>,
+    window: Window,
+    line_number: usize,
+}
+
+pub fn validate<R: BufRead>(reader: R, preamble: usize) -> Validator<R> {
+    Validator {
+        lines: reader.lines(),
+        window: Window::new(preamble),
+        line_number: 0,
+    }
+}
+
+impl<R: BufRead> Iterator for Validator<R> {
+    type Item = Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(err) => return Some(Err(err.into())),
+            };
+            self.line_number += 1;
+
+            let mut parser = Parser::new(line.trim_end());
+            parser.spaces();
+            if parser.is_empty() {
+                continue;
+            }
+
+            let number = parser.integer().and_then(|number| {
+                parser.end()?;
+                Ok(number)
+            });
+            return Some(match number {
+                Ok(number) => Ok(self.window.check(number)),
+                Err(mut err) => {
+                    err.line = self.line_number;
+                    Err(AocError::Parse(err).into())
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_validate() {
+        let events = validate("1\n2\n3\n4\n10\n".as_bytes(), 2)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            events,
+            vec![
+                Event::Preamble {
+                    position: 0,
+                    number: 1
+                },
+                Event::Preamble {
+                    position: 1,
+                    number: 2
+                },
+                Event::Valid {
+                    position: 2,
+                    number: 3
+                },
+                Event::Invalid {
+                    position: 3,
+                    number: 4
+                },
+                Event::Invalid {
+                    position: 4,
+                    number: 10
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_events_match_validate() {
+        let input = "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n";
+        let numbers = parse(input).unwrap();
+
+        assert_eq!(
+            events(&numbers, 5).collect::<Vec<_>>(),
+            validate(input.as_bytes(), 5)
+                .collect::<Result<Vec<_>>>()
+                .unwrap()
+        );
+        assert_eq!(
+            parse(" 1 \n\n2x\n").unwrap_err().to_string(),
+            "Line 3, column 2: expected end of line, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_validate_needs_two_different_numbers() {
+        let invalid = validate("5\n1\n10\n".as_bytes(), 2)
+            .filter_map(|event| event.ok())
+            .find(Event::is_invalid)
+            .unwrap();
+
+        assert_eq!(invalid.position(), 2);
+        assert_eq!(invalid.number(), 10);
+    }
+
+    #[test]
+    fn test_validate_reports_line_number() {
+        let error = validate("1\n\nfoo\n".as_bytes(), 2)
+            .find_map(|event| event.err())
+            .unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 1: expected an integer, found \"foo\""
+        );
+    }
+
+    #[test]
+    fn test_sum_overflow() {
+        let max = i64::MAX.to_string();
+        let input = format!("{}\n1\n{}\n", max, max);
+        let last = validate(input.as_bytes(), 2).last().unwrap().unwrap();
+
+        assert!(last.is_invalid());
+    }
+
+    proptest! {
+        #[test]
+        fn validate_never_panics(input in "(?s).{0,200}", preamble in 0..10usize) {
+            for event in validate(input.as_bytes(), preamble) {
+                let _ = event;
+            }
+        }
+
+        #[test]
+        fn validate_accepts_any_numbers(
+            numbers in prop::collection::vec(any::<i64>(), 0..50),
+            preamble in 0..10usize
+        ) {
+            let input = numbers.iter().map(|n| format!("{}\n", n)).collect::<String>();
+            let events = validate(input.as_bytes(), preamble).collect::<Result<Vec<_>>>().unwrap();
+            prop_assert_eq!(events.len(), numbers.len());
+        }
+    }
+}
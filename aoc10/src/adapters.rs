@@ -0,0 +1,386 @@
+use std::{collections::BTreeMap, fmt};
+
+use rand::Rng;
+use utils::{error::AocError, graph::Graph};
+
+/// Which steps in joltage the adapters accept, and the built-in adapter of the device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tolerance {
+    /// The smallest step in joltage an adapter accepts
+    pub min_step: u64,
+    /// The largest step in joltage an adapter accepts
+    pub max_step: u64,
+    /// How much higher the built-in adapter of the device is than the highest adapter
+    pub device_offset: u64,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance {
+            min_step: 1,
+            max_step: 3,
+            device_offset: 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainError {
+    /// No adapter bridges the step from `from` to `to`
+    Gap { from: u64, to: u64 },
+    /// More than one adapter (or an adapter and the outlet) has this joltage
+    Duplicate(u64),
+    /// The step from `from` to `to` is smaller than the adapters accept
+    TooClose { from: u64, to: u64 },
+    /// The number of arrangements does not fit in an u128
+    Overflow,
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainError::Gap { from, to } => write!(f, "Cannot step from {} to {} jolts", from, to),
+            ChainError::Duplicate(jolts) => write!(f, "Duplicate joltage {}", jolts),
+            ChainError::TooClose { from, to } => {
+                write!(f, "Step from {} to {} jolts is too small", from, to)
+            }
+            ChainError::Overflow => write!(f, "The number of arrangements overflowed"),
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+impl From<ChainError> for AocError {
+    fn from(err: ChainError) -> Self {
+        match err {
+            ChainError::Overflow => AocError::overflow(err.to_string()),
+            _ => AocError::invalid_input(err.to_string()),
+        }
+    }
+}
+
+/// The outlet, every adapter and the device, sorted by joltage.
+///
+/// Adapters with the same joltage are kept as separate adapters. They can never
+/// be used in the same arrangement, but each of them is a distinct choice.
+#[derive(Debug, Clone)]
+pub struct AdapterChain {
+    joltages: Vec<u64>,
+    tolerance: Tolerance,
+    /// An edge for every step the tolerance allows. The node ids are the
+    /// indices into `joltages`.
+    steps: Graph<usize>,
+}
+
+impl AdapterChain {
+    /// Fails with [`ChainError::Overflow`] if the device joltage does not fit in a u64
+    pub fn new(mut adapters: Vec<u64>, tolerance: Tolerance) -> Result<Self, ChainError> {
+        adapters.sort_unstable();
+        let device = adapters
+            .last()
+            .copied()
+            .unwrap_or(0)
+            .checked_add(tolerance.device_offset)
+            .ok_or(ChainError::Overflow)?;
+
+        let mut joltages = Vec::with_capacity(adapters.len() + 2);
+        joltages.push(0);
+        joltages.extend(adapters);
+        joltages.push(device);
+
+        let mut chain = AdapterChain {
+            joltages,
+            tolerance,
+            steps: Graph::new(),
+        };
+        for node in 0..chain.joltages.len() {
+            chain.steps.add_node(node);
+        }
+        for node in 0..chain.joltages.len() {
+            let successors = chain.successors(node).collect::<Vec<_>>();
+            for next in successors {
+                chain.steps.add_edge(node, next, ());
+            }
+        }
+
+        Ok(chain)
+    }
+
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance
+    }
+
+    /// Checks that the chain using every adapter is valid, reporting every problem
+    pub fn validate(&self) -> Result<(), Vec<ChainError>> {
+        let errors = self
+            .joltages
+            .windows(2)
+            .filter_map(|pair| {
+                let (from, to) = (pair[0], pair[1]);
+                let step = to - from;
+                if step > self.tolerance.max_step {
+                    Some(ChainError::Gap { from, to })
+                } else if step >= self.tolerance.min_step {
+                    None
+                } else if step == 0 {
+                    Some(ChainError::Duplicate(from))
+                } else {
+                    Some(ChainError::TooClose { from, to })
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// How many times each difference occurs in the chain using every adapter
+    pub fn differences(&self) -> BTreeMap<u64, usize> {
+        let mut counts = BTreeMap::new();
+        for pair in self.joltages.windows(2) {
+            *counts.entry(pair[1] - pair[0]).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// The number of distinct ways to connect the outlet to the device
+    pub fn arrangements(&self) -> Result<u128, ChainError> {
+        Ok(self.paths_to_device()?[0])
+    }
+
+    /// Lazily lists every arrangement as the joltages of the adapters used
+    pub fn arrangements_iter(&self) -> Arrangements<'_> {
+        let reaches_device = self.reaches_device();
+        let stack = if reaches_device[0] {
+            vec![(0, 1)]
+        } else {
+            vec![]
+        };
+
+        Arrangements {
+            chain: self,
+            reaches_device,
+            stack,
+        }
+    }
+
+    /// Picks one of the arrangements uniformly at random, or `None` if there are none
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Option<Vec<u64>>, ChainError> {
+        let paths = self.paths_to_device()?;
+        if paths[0] == 0 {
+            return Ok(None);
+        }
+
+        let last = self.joltages.len() - 1;
+        let mut arrangement = vec![];
+        let mut node = 0;
+
+        while node != last {
+            let mut pick = rng.gen_range(0..paths[node]);
+            node = self
+                .steps
+                .neighbours(node)
+                .find(|next| {
+                    if pick < paths[*next] {
+                        true
+                    } else {
+                        pick -= paths[*next];
+                        false
+                    }
+                })
+                .expect("the paths of the successors add up");
+
+            if node != last {
+                arrangement.push(self.joltages[node]);
+            }
+        }
+
+        Ok(Some(arrangement))
+    }
+
+    /// The indices that can directly follow `node`, for building `steps`
+    fn successors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        let from = self.joltages[node];
+        let Tolerance {
+            min_step, max_step, ..
+        } = self.tolerance;
+
+        (node + 1..self.joltages.len())
+            .take_while(move |next| self.joltages[*next] - from <= max_step)
+            .filter(move |next| self.joltages[*next] - from >= min_step)
+    }
+
+    /// For each node, the number of ways to get from it to the device
+    fn paths_to_device(&self) -> Result<Vec<u128>, ChainError> {
+        // Steps only go to higher indices, so there are no cycles to report
+        self.steps
+            .count_paths_to(self.joltages.len() - 1)
+            .map_err(|_| ChainError::Overflow)
+    }
+
+    fn reaches_device(&self) -> Vec<bool> {
+        let reaches = self.steps.reversed().reachable(self.joltages.len() - 1);
+        (0..self.joltages.len())
+            .map(|node| reaches.contains(&node))
+            .collect()
+    }
+}
+
+pub struct Arrangements<'a> {
+    chain: &'a AdapterChain,
+    reaches_device: Vec<bool>,
+    // The current path as (node, next successor to try)
+    stack: Vec<(usize, usize)>,
+}
+
+impl<'a> Iterator for Arrangements<'a> {
+    type Item = Vec<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let last = self.chain.joltages.len() - 1;
+
+        loop {
+            let (node, candidate) = *self.stack.last()?;
+
+            if node == last {
+                let arrangement = self.stack[1..self.stack.len() - 1]
+                    .iter()
+                    .map(|(node, _)| self.chain.joltages[*node])
+                    .collect();
+                self.stack.pop();
+                return Some(arrangement);
+            }
+
+            let reaches_device = &self.reaches_device;
+            let next = self
+                .chain
+                .steps
+                .neighbours(node)
+                .find(|next| *next >= candidate && reaches_device[*next]);
+
+            match next {
+                Some(next) => {
+                    self.stack.last_mut().unwrap().1 = next + 1;
+                    self.stack.push((next, next + 1));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            AdapterChain::new(vec![1, 4, 5], Tolerance::default())
+                .unwrap()
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            AdapterChain::new(vec![1, 5, 5], Tolerance::default())
+                .unwrap()
+                .validate(),
+            Err(vec![
+                ChainError::Gap { from: 1, to: 5 },
+                ChainError::Duplicate(5)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_with_tolerance() {
+        let tolerance = Tolerance {
+            min_step: 2,
+            max_step: 4,
+            device_offset: 4,
+        };
+
+        assert_eq!(
+            AdapterChain::new(vec![4, 8], tolerance).unwrap().validate(),
+            Ok(())
+        );
+        assert_eq!(
+            AdapterChain::new(vec![4, 5], tolerance).unwrap().validate(),
+            Err(vec![ChainError::TooClose { from: 4, to: 5 }])
+        );
+    }
+
+    #[test]
+    fn test_arrangements_with_tolerance() {
+        let tolerance = Tolerance {
+            min_step: 1,
+            max_step: 4,
+            device_offset: 4,
+        };
+        let chain = AdapterChain::new(vec![1, 2, 3, 4], tolerance).unwrap();
+
+        // Every subset of {1, 2, 3} combined with 4
+        assert_eq!(chain.arrangements(), Ok(8));
+        assert_eq!(chain.arrangements_iter().count(), 8);
+    }
+
+    #[test]
+    fn test_arrangements_with_duplicates() {
+        let chain = AdapterChain::new(vec![1, 2, 2], Tolerance::default()).unwrap();
+
+        assert_eq!(chain.arrangements(), Ok(4));
+        assert_eq!(
+            chain.arrangements_iter().collect::<Vec<_>>(),
+            vec![vec![1, 2], vec![1, 2], vec![2], vec![2]]
+        );
+    }
+
+    #[test]
+    fn test_arrangements_iter_skips_dead_ends() {
+        let chain = AdapterChain::new(vec![1, 2, 10], Tolerance::default()).unwrap();
+
+        assert_eq!(chain.arrangements(), Ok(0));
+        assert_eq!(chain.arrangements_iter().next(), None);
+    }
+
+    #[test]
+    fn test_arrangements_overflow() {
+        let chain = AdapterChain::new((1..=400).collect(), Tolerance::default()).unwrap();
+
+        assert_eq!(chain.arrangements(), Err(ChainError::Overflow));
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let adapters = (1..=40_000).map(|n| n * 3).collect::<Vec<u64>>();
+        let chain = AdapterChain::new(adapters.clone(), Tolerance::default()).unwrap();
+
+        assert_eq!(chain.arrangements(), Ok(1));
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(chain.sample(&mut rng), Ok(Some(adapters)));
+    }
+
+    #[test]
+    fn test_sample() {
+        let chain = AdapterChain::new(
+            vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4],
+            Tolerance::default(),
+        )
+        .unwrap();
+        let arrangements = chain.arrangements_iter().collect::<Vec<_>>();
+        let mut rng = StdRng::seed_from_u64(2020);
+
+        for _ in 0..20 {
+            let sample = chain.sample(&mut rng).unwrap().unwrap();
+            assert!(arrangements.contains(&sample));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+pub mod adapters;
+
+use anyhow::Result;
+use utils::{error::AocError, parse, Solution};
+
+use crate::adapters::{AdapterChain, Tolerance};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = AdapterChain;
+    type Answer1 = usize;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<AdapterChain> {
+        Ok(parse(input, Tolerance::default())?)
+    }
+
+    fn part1(chain: &AdapterChain) -> Result<usize> {
+        part1(chain)
+    }
+
+    fn part2(chain: &AdapterChain) -> Result<u128> {
+        part2(chain)
+    }
+}
+
+/// One adapter joltage per line
+pub fn parse(input: &str, tolerance: Tolerance) -> Result<AdapterChain, AocError> {
+    let adapters = parse::lines(input, |parser| parser.integer())?;
+
+    Ok(AdapterChain::new(adapters, tolerance)?)
+}
+
+/// The number of smallest steps multiplied by the number of largest steps
+pub fn part1(chain: &AdapterChain) -> Result<usize> {
+    chain.validate().map_err(|errors| {
+        AocError::invalid_input(
+            errors
+                .iter()
+                .map(|error| error.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    })?;
+
+    let tolerance = chain.tolerance();
+    let counts = chain.differences();
+
+    counts
+        .get(&tolerance.min_step)
+        .unwrap_or(&0)
+        .checked_mul(*counts.get(&tolerance.max_step).unwrap_or(&0))
+        .ok_or_else(|| AocError::overflow("the product of the step counts").into())
+}
+
+pub fn part2(chain: &AdapterChain) -> Result<u128> {
+    Ok(chain.arrangements().map_err(AocError::from)?)
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part2_with_tolerance() {
+        let tolerance = Tolerance {
+            min_step: 1,
+            max_step: 4,
+            device_offset: 3,
+        };
+
+        assert_eq!(
+            part2(&parse("1\n2\n3\n4\n", tolerance).unwrap()).unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_device_overflow() {
+        assert!(parse("18446744073709551615\n", Tolerance::default()).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", adapters in prop::collection::vec(any::<u64>(), 0..20)) {
+            let _ = parse(&input, Tolerance::default());
+
+            let input = adapters.iter().map(|a| format!("{}\n", a)).collect::<String>();
+            if let Ok(chain) = parse(&input, Tolerance::default()) {
+                let _ = chain.validate();
+                let _ = chain.differences();
+            }
+        }
+    }
+}
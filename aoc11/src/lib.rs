@@ -0,0 +1,238 @@
+use anyhow::{bail, Result};
+use utils::{error::AocError, parse, Solution, Vector};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<State>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        part2(input)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Empty,
+    Occupied,
+    Floor,
+}
+
+/// The layout must be a non-empty rectangle of `L` and `.`
+pub fn parse(input: &str) -> Result<Vec<Vec<State>>, AocError> {
+    let seats = parse::lines(input, |parser| {
+        let mut row = vec![];
+        while !parser.is_empty() {
+            row.push(
+                match parser.satisfy("L or .", |ch| ch == 'L' || ch == '.')? {
+                    'L' => State::Empty,
+                    _ => State::Floor,
+                },
+            );
+        }
+
+        Ok(row)
+    })?;
+
+    let width = seats
+        .first()
+        .map(Vec::len)
+        .ok_or_else(|| AocError::invalid_input("there are no seats"))?;
+    if let Some(y) = seats.iter().position(|row| row.len() != width) {
+        return Err(AocError::invalid_input(format!(
+            "row {} has {} places, expected {}",
+            y + 1,
+            seats[y].len(),
+            width
+        )));
+    }
+
+    Ok(seats)
+}
+
+pub fn part2(seats: &[Vec<State>]) -> Result<usize> {
+    let height = seats.len() as i64;
+    let width = seats.first().map_or(0, Vec::len) as i64;
+
+    let mut state = seats.to_vec();
+    let mut previous = vec![];
+
+    loop {
+        let new_state = state
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, seat)| {
+                        if *seat == State::Floor {
+                            return State::Floor;
+                        }
+
+                        let (x, y) = (x as i64, y as i64);
+                        let start = Vector(x, y);
+
+                        let num_occupied = Vector::COMPASS
+                            .iter()
+                            .filter(|direction| {
+                                for steps in 1.. {
+                                    let Vector(x, y) = start + **direction * steps;
+                                    if !(x >= 0 && y >= 0 && x < width && y < height) {
+                                        return false;
+                                    }
+
+                                    let seat = state[y as usize][x as usize];
+
+                                    match seat {
+                                        State::Empty => return false,
+                                        State::Occupied => return true,
+                                        State::Floor => {}
+                                    }
+                                }
+                                unreachable!();
+                            })
+                            .count();
+
+                        match seat {
+                            State::Empty if num_occupied == 0 => State::Occupied,
+                            State::Occupied if num_occupied >= 5 => State::Empty,
+                            otherwise => *otherwise,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if state == new_state {
+            break;
+        }
+        if new_state == previous {
+            bail!(AocError::no_solution(
+                "the seats keep changing back and forth"
+            ));
+        }
+
+        previous = std::mem::replace(&mut state, new_state);
+    }
+
+    Ok(state
+        .iter()
+        .map(|row| row.iter().filter(|el| **el == State::Occupied).count())
+        .sum())
+}
+
+pub fn part1(seats: &[Vec<State>]) -> Result<usize> {
+    let height = seats.len() as i32;
+    let width = seats.first().map_or(0, Vec::len) as i32;
+
+    let mut state = seats.to_vec();
+    let mut previous = vec![];
+
+    loop {
+        let new_state = state
+            .iter()
+            .enumerate()
+            .map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, seat)| {
+                        if *seat == State::Floor {
+                            return State::Floor;
+                        }
+
+                        let (x, y) = (x as i32, y as i32);
+
+                        let num_occupied = [
+                            (x - 1, y - 1),
+                            (x - 1, y),
+                            (x - 1, y + 1),
+                            (x, y - 1),
+                            (x, y + 1),
+                            (x + 1, y - 1),
+                            (x + 1, y),
+                            (x + 1, y + 1),
+                        ]
+                        .iter()
+                        .filter(|(x, y)| *x >= 0 && *y >= 0 && *x < width && *y < height)
+                        .filter(|(x, y)| state[*y as usize][*x as usize] == State::Occupied)
+                        .count();
+
+                        match seat {
+                            State::Empty if num_occupied == 0 => State::Occupied,
+                            State::Occupied if num_occupied >= 4 => State::Empty,
+                            otherwise => *otherwise,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if state == new_state {
+            break;
+        }
+        if new_state == previous {
+            bail!(AocError::no_solution(
+                "the seats keep changing back and forth"
+            ));
+        }
+
+        previous = std::mem::replace(&mut state, new_state);
+    }
+
+    Ok(state
+        .iter()
+        .map(|row| row.iter().filter(|el| **el == State::Occupied).count())
+        .sum())
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("L.L\nL#L\n").is_err());
+        assert!(parse("L.L\nL.\n").is_err());
+        assert_eq!(parse("L.\n\n.L\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_seats_that_never_settle() {
+        let error = part1(&parse(".LL.\nLLLL\nLLLL\n.LL.\n").unwrap()).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<AocError>(),
+            Some(&AocError::no_solution(
+                "the seats keep changing back and forth"
+            ))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[L.#\n]{0,100}") {
+            let _ = parse(&input);
+            let _ = parse(&noise);
+        }
+
+        #[test]
+        fn parse_accepts_rectangles(rows in (1..20usize).prop_flat_map(|width| {
+            prop::collection::vec(prop::string::string_regex(&format!("[L.]{{{}}}", width)).unwrap(), 1..20)
+        })) {
+            prop_assert_eq!(parse(&rows.join("\n")).unwrap().len(), rows.len());
+        }
+    }
+}
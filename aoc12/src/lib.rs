@@ -0,0 +1,69 @@
+pub mod navigation;
+pub mod route;
+
+use anyhow::{bail, Result};
+
+use utils::{error::AocError, Solution};
+
+use crate::{
+    navigation::{parse, Instruction, Ship},
+    route::Route,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse(input).map_err(AocError::from)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i64> {
+        part2(input)
+    }
+}
+
+pub fn record(instructions: &[Instruction], model: &str) -> Result<Route> {
+    Ok(match model {
+        "heading" => Ship::with_heading().record(instructions)?,
+        "waypoint" => Ship::with_waypoint().record(instructions)?,
+        _ => bail!("Unknown model {:?}, expected heading or waypoint", model),
+    })
+}
+
+pub fn part1(instructions: &[Instruction]) -> Result<i64> {
+    let mut ship = Ship::with_heading();
+    ship.run(instructions)?;
+
+    Ok(ship.distance()?)
+}
+
+pub fn part2(instructions: &[Instruction]) -> Result<i64> {
+    let mut ship = Ship::with_waypoint();
+    ship.run(instructions)?;
+
+    Ok(ship.distance()?)
+}
+
+utils::example_tests!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day::parse("F10\nX3\n").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AocError>(),
+            Some(AocError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,298 @@
+use std::fmt;
+
+use utils::{
+    error::AocError,
+    parse::{self, ParseError, Parser},
+    Vector,
+};
+
+use crate::route::{Route, RoutePoint};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    North(i64),
+    South(i64),
+    East(i64),
+    West(i64),
+    TurnLeft(i64),
+    TurnRight(i64),
+    MoveForward(i64),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Instruction::*;
+
+        match self {
+            North(val) => write!(f, "N{}", val),
+            South(val) => write!(f, "S{}", val),
+            East(val) => write!(f, "E{}", val),
+            West(val) => write!(f, "W{}", val),
+            TurnLeft(val) => write!(f, "L{}", val),
+            TurnRight(val) => write!(f, "R{}", val),
+            MoveForward(val) => write!(f, "F{}", val),
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse::lines(input, |parser| {
+        parser.spaces();
+        let instruction = parse_instruction(parser)?;
+        parser.spaces();
+
+        Ok(instruction)
+    })
+}
+
+fn parse_instruction(parser: &mut Parser) -> Result<Instruction, ParseError> {
+    use Instruction::*;
+
+    let start = parser.clone();
+    let command = parser.char()?;
+    let value = parser.integer::<i64>()?;
+
+    Ok(match command {
+        'N' => North(value),
+        'S' => South(value),
+        'E' => East(value),
+        'W' => West(value),
+        'L' | 'R' if value % 90 != 0 => {
+            return Err(start.error("a turn by a multiple of 90 degrees"))
+        }
+        'L' => TurnLeft(value),
+        'R' => TurnRight(value),
+        'F' => MoveForward(value),
+        _ => return Err(start.error("one of the commands NSEWLRF")),
+    })
+}
+
+/// How a ship interprets the instructions. Moving returns `None` if a
+/// coordinate overflows.
+pub trait Navigation {
+    /// Handles N, S, E and W
+    fn shift(&mut self, position: &mut Vector, direction: Vector, amount: i64) -> Option<()>;
+    /// Handles L and R, where positive degrees are clockwise
+    fn turn(&mut self, degrees: i64);
+    /// Handles F
+    fn forward(&self, position: &mut Vector, amount: i64) -> Option<()>;
+    /// The waypoint relative to the ship, if this kind of navigation uses one
+    fn waypoint(&self) -> Option<Vector> {
+        None
+    }
+}
+
+/// The ship moves itself, and turning changes the direction it faces (part 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Heading(pub Vector);
+
+impl Navigation for Heading {
+    fn shift(&mut self, position: &mut Vector, direction: Vector, amount: i64) -> Option<()> {
+        *position = position.checked_add(direction.checked_mul(amount)?)?;
+        Some(())
+    }
+
+    fn turn(&mut self, degrees: i64) {
+        self.0 = self.0.rotate(degrees);
+    }
+
+    fn forward(&self, position: &mut Vector, amount: i64) -> Option<()> {
+        *position = position.checked_add(self.0.checked_mul(amount)?)?;
+        Some(())
+    }
+}
+
+/// The ship moves a waypoint relative to itself, and follows it forward (part 2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Waypoint(pub Vector);
+
+impl Navigation for Waypoint {
+    fn shift(&mut self, _position: &mut Vector, direction: Vector, amount: i64) -> Option<()> {
+        self.0 = self.0.checked_add(direction.checked_mul(amount)?)?;
+        Some(())
+    }
+
+    fn turn(&mut self, degrees: i64) {
+        self.0 = self.0.rotate(degrees);
+    }
+
+    fn forward(&self, position: &mut Vector, amount: i64) -> Option<()> {
+        *position = position.checked_add(self.0.checked_mul(amount)?)?;
+        Some(())
+    }
+
+    fn waypoint(&self) -> Option<Vector> {
+        Some(self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ship<N> {
+    pub position: Vector,
+    pub navigation: N,
+}
+
+impl Ship<Heading> {
+    /// A ship starting at the origin facing east
+    pub fn with_heading() -> Self {
+        Ship::new(Heading(Vector::EAST))
+    }
+}
+
+impl Ship<Waypoint> {
+    /// A ship starting at the origin with the waypoint 10 east and 1 north
+    pub fn with_waypoint() -> Self {
+        Ship::new(Waypoint(Vector::EAST * 10 + Vector::NORTH))
+    }
+}
+
+impl<N: Navigation> Ship<N> {
+    pub fn new(navigation: N) -> Self {
+        Ship {
+            position: Vector(0, 0),
+            navigation,
+        }
+    }
+
+    pub fn step(&mut self, instruction: Instruction) -> Result<(), AocError> {
+        use Instruction::*;
+
+        let position = &mut self.position;
+        let moved = match instruction {
+            North(val) => self.navigation.shift(position, Vector::NORTH, val),
+            South(val) => self.navigation.shift(position, Vector::SOUTH, val),
+            East(val) => self.navigation.shift(position, Vector::EAST, val),
+            West(val) => self.navigation.shift(position, Vector::WEST, val),
+            TurnLeft(val) => {
+                self.navigation.turn(-val);
+                Some(())
+            }
+            TurnRight(val) => {
+                self.navigation.turn(val);
+                Some(())
+            }
+            MoveForward(val) => self.navigation.forward(position, val),
+        };
+
+        moved.ok_or_else(|| AocError::overflow(format!("the ship went too far at {}", instruction)))
+    }
+
+    pub fn run(&mut self, instructions: &[Instruction]) -> Result<(), AocError> {
+        for instruction in instructions {
+            self.step(*instruction)?;
+        }
+
+        Ok(())
+    }
+
+    /// Like `run`, but records where the ship (and waypoint) is after every step
+    pub fn record(&mut self, instructions: &[Instruction]) -> Result<Route, AocError> {
+        let mut route = Route {
+            points: vec![self.point(None)],
+        };
+
+        for instruction in instructions {
+            self.step(*instruction)?;
+            route.points.push(self.point(Some(*instruction)));
+        }
+
+        Ok(route)
+    }
+
+    fn point(&self, instruction: Option<Instruction>) -> RoutePoint {
+        RoutePoint {
+            instruction,
+            position: self.position,
+            waypoint: self
+                .navigation
+                .waypoint()
+                .map(|waypoint| self.position + waypoint),
+        }
+    }
+
+    /// The Manhattan distance from the origin
+    pub fn distance(&self) -> Result<i64, AocError> {
+        self.position
+            .checked_manhattan()
+            .ok_or_else(|| AocError::overflow("the distance of the ship"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("F10\n\nX3\n"),
+            Err(ParseError {
+                line: 3,
+                column: 1,
+                expected: "one of the commands NSEWLRF".to_owned(),
+                found: "\"X3\"".to_owned(),
+            })
+        );
+        assert_eq!(
+            parse("L45").unwrap_err().expected,
+            "a turn by a multiple of 90 degrees"
+        );
+        assert_eq!(parse("  Ø12").unwrap_err().column, 3);
+        assert!(parse("F").is_err());
+        assert!(parse("F1 2").is_err());
+    }
+
+    #[test]
+    fn test_large_turns() {
+        let mut ship = Ship::with_heading();
+        ship.run(&parse("R450\nF1\nL630\nF2").unwrap()).unwrap();
+
+        assert_eq!(ship.position, Vector(-2, 1));
+    }
+
+    #[test]
+    fn test_waypoint_turn() {
+        let mut ship = Ship::with_waypoint();
+        ship.step(Instruction::TurnRight(-630)).unwrap();
+
+        assert_eq!(ship.navigation, Waypoint(Vector(1, 10)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut ship = Ship::with_waypoint();
+        assert_eq!(
+            ship.run(&parse("F9223372036854775807").unwrap()),
+            Err(AocError::overflow(
+                "the ship went too far at F9223372036854775807"
+            ))
+        );
+
+        let mut ship = Ship::with_heading();
+        ship.run(
+            &parse(
+                "F9223372036854775807
+S1",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(ship.distance().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[NSEWLRF0-9 \n]{0,100}") {
+            let _ = parse(&input);
+            let _ = parse(&noise);
+        }
+
+        #[test]
+        fn parse_accepts_instructions(
+            instructions in prop::collection::vec("[NSEWF][0-9]{1,5}|[LR](0|90|180|270|360)", 0..20)
+        ) {
+            prop_assert_eq!(parse(&instructions.join("\n")).unwrap().len(), instructions.len());
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use std::fmt::Write;
+
+use utils::Vector;
+
+use crate::navigation::Instruction;
+
+/// Where the ship is after an instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoutePoint {
+    /// The instruction that got the ship here, `None` for the starting point
+    pub instruction: Option<Instruction>,
+    pub position: Vector,
+    /// Where the waypoint is, not relative to the ship
+    pub waypoint: Option<Vector>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub points: Vec<RoutePoint>,
+}
+
+const MARGIN: i64 = 10;
+
+impl Route {
+    /// One line per point with the columns step,instruction,x,y,waypoint_x,waypoint_y.
+    /// The waypoint columns are empty when there is no waypoint.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,instruction,x,y,waypoint_x,waypoint_y\n");
+
+        for (step, point) in self.points.iter().enumerate() {
+            let instruction = point
+                .instruction
+                .map(|instruction| instruction.to_string())
+                .unwrap_or_default();
+            let (waypoint_x, waypoint_y) = point
+                .waypoint
+                .map(|waypoint| (waypoint.x().to_string(), waypoint.y().to_string()))
+                .unwrap_or_default();
+
+            writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                step,
+                instruction,
+                point.position.x(),
+                point.position.y(),
+                waypoint_x,
+                waypoint_y
+            )
+            .unwrap();
+        }
+
+        csv
+    }
+
+    /// Draws the route of the ship as a solid line with a green start and a red
+    /// end, and the track of the waypoint as a dashed line
+    pub fn to_svg(&self) -> String {
+        let positions = self
+            .points
+            .iter()
+            .map(|point| point.position)
+            .collect::<Vec<_>>();
+        let waypoints = self
+            .points
+            .iter()
+            .filter_map(|point| point.waypoint)
+            .collect::<Vec<_>>();
+
+        let all = positions.iter().chain(waypoints.iter());
+        let min_x = all
+            .clone()
+            .map(|v| v.x())
+            .min()
+            .unwrap_or(0)
+            .saturating_sub(MARGIN);
+        let min_y = all
+            .clone()
+            .map(|v| v.y())
+            .min()
+            .unwrap_or(0)
+            .saturating_sub(MARGIN);
+        let max_x = all
+            .clone()
+            .map(|v| v.x())
+            .max()
+            .unwrap_or(0)
+            .saturating_add(MARGIN);
+        let max_y = all.map(|v| v.y()).max().unwrap_or(0).saturating_add(MARGIN);
+        // The positions can span the whole i64 range
+        let width = i128::from(max_x) - i128::from(min_x);
+        let height = i128::from(max_y) - i128::from(min_y);
+        let radius = (width.max(height) / 100).max(1);
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            min_x, min_y, width, height
+        )
+        .unwrap();
+
+        if !waypoints.is_empty() {
+            writeln!(
+                svg,
+                r#"  <polyline class="waypoint" points="{}" fill="none" stroke="orange" stroke-dasharray="4 2" vector-effect="non-scaling-stroke"/>"#,
+                points(&waypoints)
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            svg,
+            r#"  <polyline class="ship" points="{}" fill="none" stroke="steelblue" vector-effect="non-scaling-stroke"/>"#,
+            points(&positions)
+        )
+        .unwrap();
+
+        if let (Some(start), Some(end)) = (positions.first(), positions.last()) {
+            writeln!(
+                svg,
+                r#"  <circle class="start" cx="{}" cy="{}" r="{}" fill="green"/>"#,
+                start.x(),
+                start.y(),
+                radius
+            )
+            .unwrap();
+            writeln!(
+                svg,
+                r#"  <circle class="end" cx="{}" cy="{}" r="{}" fill="red"/>"#,
+                end.x(),
+                end.y(),
+                radius
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+fn points(vectors: &[Vector]) -> String {
+    vectors
+        .iter()
+        .map(|v| format!("{},{}", v.x(), v.y()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::navigation::{parse, Ship};
+
+    #[test]
+    fn test_record() {
+        let mut ship = Ship::with_waypoint();
+        let route = ship.record(&parse("F10\nN3").unwrap()).unwrap();
+
+        assert_eq!(route.points.len(), 3);
+        assert_eq!(route.points[1].position, utils::Vector(100, -10));
+        assert_eq!(route.points[2].waypoint, Some(utils::Vector(110, -14)));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let mut ship = Ship::with_heading();
+        let route = ship.record(&parse("F10\nR90").unwrap()).unwrap();
+
+        assert_eq!(
+            route.to_csv(),
+            "step,instruction,x,y,waypoint_x,waypoint_y
+0,,0,0,,
+1,F10,10,0,,
+2,R90,10,0,,
+"
+        );
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let mut ship = Ship::with_waypoint();
+        let svg = ship.record(&parse("F10\nN3").unwrap()).unwrap().to_svg();
+
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-10 -24 130 34">"#)
+        );
+        assert!(svg.contains(r#"class="ship" points="0,0 100,-10 100,-10""#));
+        assert!(svg.contains(r#"class="waypoint" points="10,-1 110,-11 110,-14""#));
+        assert!(svg.contains(r#"<circle class="end" cx="100" cy="-10""#));
+    }
+
+    #[test]
+    fn test_to_svg_far_away() {
+        let mut ship = Ship::with_heading();
+        let route = ship
+            .record(
+                &parse("N9223372036854775807\nS9223372036854775807\nS9223372036854775807").unwrap(),
+            )
+            .unwrap();
+
+        assert!(route.to_svg().starts_with(&format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-10 {} 20 {}">"#,
+            i64::MIN,
+            u64::MAX
+        )));
+    }
+}
@@ -0,0 +1,47 @@
+pub mod schedule;
+
+use anyhow::{bail, Result};
+
+use utils::{error::AocError, Solution};
+
+use crate::schedule::Schedule;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Schedule;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Schedule> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(schedule: &Schedule) -> Result<u64> {
+        part1(schedule)
+    }
+
+    fn part2(schedule: &Schedule) -> Result<u64> {
+        part2(schedule)
+    }
+}
+
+pub fn part1(schedule: &Schedule) -> Result<u64> {
+    if schedule.busses.is_empty() {
+        bail!(AocError::invalid_input("there are no busses"));
+    }
+    let (id, departure) = schedule
+        .next_departures(schedule.earliest)
+        .into_iter()
+        .min_by_key(|(_, departure)| *departure)
+        .ok_or_else(|| AocError::no_solution("no bus departs before the end of time"))?;
+
+    id.checked_mul(departure - schedule.earliest)
+        .ok_or_else(|| AocError::overflow("the bus id times the wait").into())
+}
+
+pub fn part2(schedule: &Schedule) -> Result<u64> {
+    Ok(schedule.align_all()?.timestamp)
+}
+
+utils::example_tests!();
@@ -0,0 +1,241 @@
+use std::{convert::TryFrom, ops::Range, str::FromStr};
+
+use tracing::debug;
+
+use utils::{
+    error::AocError,
+    number_theory::{crt, lcm},
+    parse::Parser,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bus {
+    pub id: u64,
+    /// The position of the bus in the list
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    /// The earliest timestamp you could depart
+    pub earliest: u64,
+    pub busses: Vec<Bus>,
+}
+
+/// A timestamp where the busses line up, and how often that happens again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub timestamp: u64,
+    pub period: u64,
+}
+
+/// The earliest timestamp on the first line, and the bus ids separated by
+/// commas on the second, with `x` for a slot without a bus
+impl FromStr for Schedule {
+    type Err = AocError;
+
+    fn from_str(input: &str) -> Result<Self, AocError> {
+        let mut parser = Parser::new(input);
+        parser.spaces();
+        let earliest = parser.integer()?;
+        parser.spaces();
+        parser.try_literal("\r");
+        parser.literal("\n")?;
+        parser.spaces();
+
+        let slots = parser.separated(",", |parser| {
+            if parser.try_literal("x") {
+                return Ok(None);
+            }
+            let start = parser.clone();
+            match parser.integer()? {
+                0 => Err(start.error("a positive bus id")),
+                id => Ok(Some(id)),
+            }
+        })?;
+        parser.take_while(char::is_whitespace);
+        parser.end()?;
+
+        let busses = slots
+            .into_iter()
+            .enumerate()
+            .filter_map(|(offset, id)| {
+                Some(Bus {
+                    id: id?,
+                    offset: offset as u64,
+                })
+            })
+            .collect();
+
+        Ok(Schedule { earliest, busses })
+    }
+}
+
+impl Schedule {
+    /// The first departure of each bus at or after `t`, as `(id, departure)`.
+    /// Busses whose next departure does not fit in an u64 are left out.
+    pub fn next_departures(&self, t: u64) -> Vec<(u64, u64)> {
+        self.busses
+            .iter()
+            .filter_map(|bus| Some((bus.id, t.checked_add((bus.id - t % bus.id) % bus.id)?)))
+            .collect()
+    }
+
+    /// Every departure in `window`, ordered by time, as `(departure, id)`
+    pub fn timetable(&self, window: Range<u64>) -> Vec<(u64, u64)> {
+        let mut departures = self
+            .next_departures(window.start)
+            .into_iter()
+            .flat_map(|(id, first)| {
+                (first..window.end)
+                    .step_by(id as usize)
+                    .map(move |departure| (departure, id))
+            })
+            .collect::<Vec<_>>();
+
+        departures.sort_unstable();
+        departures
+    }
+
+    /// The earliest timestamp `t` where bus `id` departs at `t + offset` for
+    /// every `(id, offset)`. Every id must be in the schedule.
+    pub fn align(&self, offsets: &[(u64, u64)]) -> Result<Alignment, AocError> {
+        if let Some((id, _)) = offsets
+            .iter()
+            .find(|(id, _)| !self.busses.iter().any(|bus| bus.id == *id))
+        {
+            return Err(AocError::invalid_input(format!(
+                "bus {} is not in the schedule",
+                id
+            )));
+        }
+
+        let congruences = offsets
+            .iter()
+            .map(|(id, offset)| (-i128::from(*offset), i128::from(*id)))
+            .collect::<Vec<_>>();
+        debug!(?congruences, "solving");
+
+        let (timestamp, period) = crt(&congruences)?;
+        debug!(timestamp, period, "aligned");
+
+        Ok(Alignment {
+            timestamp: to_u64(timestamp)?,
+            period: to_u64(period)?,
+        })
+    }
+
+    /// The earliest timestamp where every bus departs at its position in the list
+    pub fn align_all(&self) -> Result<Alignment, AocError> {
+        self.align(
+            &self
+                .busses
+                .iter()
+                .map(|bus| (bus.id, bus.offset))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// How long it takes before the departures repeat themselves
+    pub fn period(&self) -> Result<u64, AocError> {
+        let period = self
+            .busses
+            .iter()
+            .try_fold(1, |period, bus| lcm(period, i128::from(bus.id)))
+            .ok_or_else(|| AocError::overflow("the period"))?;
+
+        to_u64(period)
+    }
+}
+
+fn to_u64(value: i128) -> Result<u64, AocError> {
+    u64::try_from(value)
+        .map_err(|_| AocError::overflow(format!("{} does not fit in an u64", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn example() -> Schedule {
+        "939\n7,13,x,x,59,x,31,19".parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let schedule = example();
+
+        assert_eq!(schedule.earliest, 939);
+        assert_eq!(schedule.busses[2], Bus { id: 59, offset: 4 });
+        assert!("939\n7,y".parse::<Schedule>().is_err());
+        assert!("939".parse::<Schedule>().is_err());
+
+        match "939\n7,0,x\n".parse::<Schedule>() {
+            Err(AocError::Parse(err)) => {
+                assert_eq!((err.line, err.column), (2, 3));
+                assert_eq!(err.expected, "a positive bus id");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_next_departures() {
+        let departures = example().next_departures(939);
+
+        assert!(departures.contains(&(59, 944)));
+        assert!(departures.contains(&(7, 945)));
+        assert_eq!(example().next_departures(0)[0], (7, 0));
+    }
+
+    #[test]
+    fn test_timetable() {
+        assert_eq!(
+            example().timetable(940..950),
+            vec![(944, 59), (945, 7), (949, 13)]
+        );
+    }
+
+    #[test]
+    fn test_align() {
+        let schedule = example();
+
+        assert_eq!(
+            schedule.align(&[(7, 0), (13, 1)]).unwrap(),
+            Alignment {
+                timestamp: 77,
+                period: 91
+            }
+        );
+        assert!(schedule.align(&[(17, 0)]).is_err());
+        assert_eq!(schedule.align_all().unwrap().timestamp, 1068781);
+    }
+
+    #[test]
+    fn test_period() {
+        assert_eq!(example().period().unwrap(), 7 * 13 * 59 * 31 * 19);
+    }
+
+    proptest! {
+        #[test]
+        fn parse_never_panics(input in "(?s).{0,200}", noise in "[0-9x,\n]{0,100}") {
+            let _ = input.parse::<Schedule>();
+            let _ = noise.parse::<Schedule>();
+        }
+
+        #[test]
+        fn parse_accepts_schedules(
+            earliest in any::<u32>(),
+            busses in prop::collection::vec(prop::option::of(1..1000u64), 1..10)
+        ) {
+            let busses = busses
+                .iter()
+                .map(|bus| bus.map_or("x".to_owned(), |id| id.to_string()))
+                .collect::<Vec<_>>()
+                .join(",");
+            let input = format!("{}\n{}\n", earliest, busses);
+            prop_assert!(input.parse::<Schedule>().is_ok());
+        }
+    }
+}
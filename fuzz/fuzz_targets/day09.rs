@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for event in aoc09::xmas::validate(input.as_bytes(), 25) {
+        let _ = event;
+    }
+});
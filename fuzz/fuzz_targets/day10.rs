@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(chain) = aoc10::parse(input, aoc10::adapters::Tolerance::default()) {
+        let _ = chain.validate();
+        let _ = chain.differences();
+    }
+});
@@ -0,0 +1,88 @@
+use std::any::{type_name, Any};
+
+use anyhow::{anyhow, Result};
+use utils::Solution;
+
+/// A parsed input, which only the day that parsed it knows the type of
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// A registered day, with the input and answers type erased so every day fits
+/// in the same table
+pub struct Day {
+    pub number: u32,
+    pub parse: fn(&str) -> Result<Parsed>,
+    /// Only takes inputs parsed by `parse`
+    pub part1: fn(&Parsed) -> Result<String>,
+    pub part2: fn(&Parsed) -> Result<String>,
+    /// The Rust types of the answers, like `i64`
+    pub types: [&'static str; 2],
+}
+
+impl Day {
+    fn new<S: Solution>(number: u32) -> Self
+    where
+        S::Input: Send + Sync + 'static,
+    {
+        Day {
+            number,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+            types: [type_name::<S::Answer1>(), type_name::<S::Answer2>()],
+        }
+    }
+
+    /// The crate name, like aoc01
+    pub fn name(&self) -> String {
+        format!("aoc{:02}", self.number)
+    }
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed>
+where
+    S::Input: Send + Sync + 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn input<S: Solution>(parsed: &Parsed) -> Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref()
+        .ok_or_else(|| anyhow!("The input was parsed by another day"))
+}
+
+fn part1<S: Solution>(parsed: &Parsed) -> Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part1(input::<S>(parsed)?)?.to_string())
+}
+
+fn part2<S: Solution>(parsed: &Parsed) -> Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part2(input::<S>(parsed)?)?.to_string())
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::new::<aoc01::Day>(1),
+        Day::new::<aoc02::Day>(2),
+        Day::new::<aoc03::Day>(3),
+        Day::new::<aoc04::Day>(4),
+        Day::new::<aoc05::Day>(5),
+        Day::new::<aoc06::Day>(6),
+        Day::new::<aoc07::Day>(7),
+        Day::new::<aoc08::Day>(8),
+        Day::new::<aoc09::Day>(9),
+        Day::new::<aoc10::Day>(10),
+        Day::new::<aoc11::Day>(11),
+        Day::new::<aoc12::Day>(12),
+        Day::new::<aoc13::Day>(13),
+        // new-day adds days above this line
+    ]
+}
@@ -0,0 +1,798 @@
+//! Seeded generators for valid puzzle inputs. `size` is roughly the number of
+//! lines, and is clamped to what a day can use. Where the answers follow from
+//! how the input was built they are returned as well; otherwise they are left
+//! as `None`. Every input is run through the day's own parser before it is
+//! returned.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+use aoc02::policy::{CountRange, PasswordPolicy, PositionalXor};
+use aoc05::boarding_pass::Plane;
+use aoc08::OpCode;
+use aoc11::State;
+use aoc12::navigation::Instruction;
+use utils::number_theory::crt;
+
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Generated {
+    fn unsolved(input: String) -> Self {
+        Generated {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+}
+
+type Generator = fn(&mut StdRng, usize) -> Result<Generated>;
+
+/// The generator for a day, if there is one
+pub fn generator(day: u32) -> Option<Generator> {
+    let generator: Generator = match day {
+        1 => expense_report,
+        2 => passwords,
+        3 => hill,
+        4 => passports,
+        5 => boarding_passes,
+        6 => customs_answers,
+        7 => bag_rules,
+        8 => console_program,
+        9 => xmas,
+        10 => adapters,
+        11 => seat_layout,
+        12 => navigation,
+        13 => bus_schedule,
+        _ => return None,
+    };
+
+    Some(generator)
+}
+
+/// Filler expenses are all above 1010, so no two of them reach 2020 together.
+/// The planted pair and triple use numbers from 505 to 1009, and fillers that
+/// would complete a pair with one of them are left out.
+fn expense_report(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let size = size.clamp(5, 1000);
+
+    let (pair, triple) = loop {
+        let a = rng.gen_range(505..1010);
+        let x = rng.gen_range(505..1010);
+        let y = rng.gen_range(505..1010);
+        let z = 2020 - x - y;
+        let small = [a, x, y, z];
+
+        let distinct = small.iter().collect::<HashSet<_>>().len() == small.len();
+        let twice = small
+            .iter()
+            .any(|s| small.iter().any(|t| s != t && 2 * s + t == 2020));
+        if (505..1010).contains(&z) && distinct && !twice {
+            break ([a, 2020 - a], [x, y, z]);
+        }
+    };
+
+    let taken = pair
+        .iter()
+        .chain(triple.iter())
+        .map(|n| 2020 - n)
+        .collect::<HashSet<_>>();
+    let mut numbers = (1011..2020)
+        .filter(|n| !taken.contains(n))
+        .collect::<Vec<i64>>();
+    numbers.shuffle(rng);
+    numbers.truncate(size - 5);
+    numbers.extend(pair.iter().chain(triple.iter()));
+    numbers.shuffle(rng);
+
+    let input = lines(&numbers);
+    ensure!(aoc01::parse(&input)?.len() == size, "Lost an expense");
+
+    Ok(Generated {
+        input,
+        part1: Some((pair[0] * pair[1]).to_string()),
+        part2: Some(triple.iter().product::<i64>().to_string()),
+    })
+}
+
+fn passwords(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let first = rng.gen_range(1..10);
+        let second = rng.gen_range(first..=15);
+        let letter = rng.gen_range(b'a'..=b'e') as char;
+        let password = (0..rng.gen_range(1..=20))
+            .map(|_| rng.gen_range(b'a'..=b'e') as char)
+            .collect::<String>();
+        writeln!(input, "{}-{} {}: {}", first, second, letter, password)?;
+    }
+
+    let entries = aoc02::policy::parse(&input)?;
+    let valid = |policy: &dyn PasswordPolicy| {
+        entries
+            .iter()
+            .filter(|entry| policy.is_valid(entry))
+            .count()
+            .to_string()
+    };
+
+    Ok(Generated {
+        part1: Some(valid(&CountRange)),
+        part2: Some(valid(&PositionalXor)),
+        input,
+    })
+}
+
+fn hill(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let input = grid(rng, size.max(1), 31, &[('.', 3), ('#', 1)]);
+    aoc03::hill::Hill::new(&input)?;
+
+    Ok(Generated::unsolved(input))
+}
+
+/// A third of the passports are valid, a third have an invalid field and the
+/// rest miss a required field
+fn passports(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    const INVALID: [(&str, &str); 7] = [
+        ("byr", "1919"),
+        ("iyr", "2021"),
+        ("eyr", "2031"),
+        ("hgt", "194cm"),
+        ("hcl", "#12345g"),
+        ("ecl", "red"),
+        ("pid", "0123456789"),
+    ];
+
+    let size = size.max(1);
+    let (mut complete, mut valid) = (0, 0);
+    let mut passports = vec![];
+
+    for _ in 0..size {
+        let mut fields = vec![
+            ("byr", rng.gen_range(1920..=2002).to_string()),
+            ("iyr", rng.gen_range(2010..=2020).to_string()),
+            ("eyr", rng.gen_range(2020..=2030).to_string()),
+            (
+                "hgt",
+                if rng.gen() {
+                    format!("{}cm", rng.gen_range(150..=193))
+                } else {
+                    format!("{}in", rng.gen_range(59..=76))
+                },
+            ),
+            ("hcl", format!("#{:06x}", rng.gen_range(0..0x100_0000))),
+            (
+                "ecl",
+                ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+                    .choose(rng)
+                    .unwrap()
+                    .to_string(),
+            ),
+            ("pid", format!("{:09}", rng.gen_range(0..1_000_000_000))),
+        ];
+        if rng.gen() {
+            fields.push(("cid", rng.gen_range(1..1000).to_string()));
+        }
+
+        match rng.gen_range(0..3) {
+            0 => {
+                complete += 1;
+                valid += 1;
+            }
+            1 => {
+                complete += 1;
+                let (key, value) = INVALID.choose(rng).unwrap();
+                let field = fields.iter_mut().find(|(field, _)| field == key).unwrap();
+                field.1 = value.to_string();
+            }
+            _ => {
+                let key = REQUIRED.choose(rng).unwrap();
+                fields.retain(|(field, _)| field != key);
+            }
+        }
+        fields.shuffle(rng);
+
+        let mut passport = String::new();
+        for (index, (key, value)) in fields.iter().enumerate() {
+            let separator = match index {
+                0 => "",
+                _ if rng.gen_ratio(1, 4) => "\n",
+                _ => " ",
+            };
+            write!(passport, "{}{}:{}", separator, key, value)?;
+        }
+        passports.push(passport);
+    }
+
+    let input = passports.join("\n\n") + "\n";
+    ensure!(aoc04::parse(&input)?.len() == size, "Lost a passport");
+
+    Ok(Generated {
+        input,
+        part1: Some(complete.to_string()),
+        part2: Some(valid.to_string()),
+    })
+}
+
+/// A full run of seat ids with a single one missing
+fn boarding_passes(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let plane = Plane::default();
+    let size = size.clamp(2, plane.seats() as usize - 3) as u32;
+
+    let first = rng.gen_range(1..plane.seats() - size - 1);
+    let last = first + size;
+    let missing = rng.gen_range(first + 1..last);
+
+    let mut passes = (first..=last)
+        .filter(|id| *id != missing)
+        .map(|id| plane.pass(id))
+        .collect::<Result<Vec<_>, _>>()?;
+    passes.shuffle(rng);
+
+    let input = lines(&passes);
+    aoc05::parse(&input, plane)?;
+
+    Ok(Generated {
+        input,
+        part1: Some(last.to_string()),
+        part2: Some(missing.to_string()),
+    })
+}
+
+fn customs_answers(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let mut groups = vec![];
+    for _ in 0..size.max(1) {
+        let people = (0..rng.gen_range(1..=5))
+            .map(|_| {
+                let mut questions = ('a'..='z').collect::<Vec<_>>();
+                questions.shuffle(rng);
+                questions[..rng.gen_range(1..=26)]
+                    .iter()
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        groups.push(people.join("\n"));
+    }
+
+    let input = groups.join("\n\n") + "\n";
+    let groups = aoc06::answers::parse(&input)?;
+    let count = |set: fn(&aoc06::answers::Group) -> aoc06::answers::AnswerSet| {
+        groups
+            .iter()
+            .map(|group| set(group).len())
+            .sum::<usize>()
+            .to_string()
+    };
+
+    Ok(Generated {
+        part1: Some(count(aoc06::answers::Group::anyone)),
+        part2: Some(count(aoc06::answers::Group::everyone)),
+        input,
+    })
+}
+
+/// The bags form a DAG, where a bag only holds bags after it in a random
+/// order. Shiny gold is placed near the end so that the count of bags inside
+/// it stays small.
+fn bag_rules(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    const ADJECTIVES: [&str; 16] = [
+        "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "muted",
+        "pale", "plaid", "posh", "striped", "vibrant", "wavy",
+    ];
+    const COLORS: [&str; 16] = [
+        "aqua", "beige", "black", "blue", "bronze", "coral", "crimson", "gold", "green", "lime",
+        "olive", "orange", "plum", "red", "tan", "white",
+    ];
+
+    let mut names = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLORS
+                .iter()
+                .map(move |color| format!("{} {}", adjective, color))
+        })
+        .collect::<Vec<_>>();
+    names.shuffle(rng);
+    names.truncate(size.clamp(1, names.len()) - 1);
+    let gold = names.len().saturating_sub(6);
+    names.insert(gold, "shiny gold".to_owned());
+
+    let contents = (0..names.len())
+        .map(|bag| {
+            let mut later = (bag + 1..names.len()).collect::<Vec<_>>();
+            later.shuffle(rng);
+            later.truncate(rng.gen_range(0..=3));
+            later
+                .into_iter()
+                .map(|inner| (inner, rng.gen_range(1..=4)))
+                .collect::<Vec<(usize, u64)>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut holders = HashSet::new();
+    let mut to_check = vec![gold];
+    while let Some(bag) = to_check.pop() {
+        for (outer, content) in contents.iter().enumerate() {
+            if content.iter().any(|(inner, _)| *inner == bag) && holders.insert(outer) {
+                to_check.push(outer);
+            }
+        }
+    }
+
+    // A bag only holds later bags, so going backwards their totals are known
+    let mut inside = vec![0u64; names.len()];
+    for bag in (gold..names.len()).rev() {
+        inside[bag] = contents[bag]
+            .iter()
+            .map(|(inner, count)| count * (1 + inside[*inner]))
+            .sum();
+    }
+
+    let mut rules = names
+        .iter()
+        .zip(&contents)
+        .map(|(name, content)| {
+            let content = if content.is_empty() {
+                "no other bags".to_owned()
+            } else {
+                content
+                    .iter()
+                    .map(|(inner, count)| {
+                        let plural = if *count == 1 { "" } else { "s" };
+                        format!("{} {} bag{}", count, names[*inner], plural)
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            format!("{} bags contain {}.", name, content)
+        })
+        .collect::<Vec<_>>();
+    rules.shuffle(rng);
+
+    let input = lines(&rules);
+    ensure!(aoc07::parse(&input)?.len() == names.len(), "Lost a rule");
+
+    Ok(Generated {
+        input,
+        part1: Some(holders.len().to_string()),
+        part2: Some(inside[gold].to_string()),
+    })
+}
+
+/// A program that would run straight through, except for one backwards jump.
+/// The other jumps skip a single `acc` and every `nop` is `nop +0`, so
+/// swapping any of them still loops, and the backwards jump is the only fix.
+fn console_program(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let size = size.max(2);
+    let broken = rng.gen_range(size / 2..size);
+
+    let mut code = vec![];
+    while code.len() < size {
+        let index = code.len();
+        if index == broken {
+            code.push(OpCode::Jmp(-(rng.gen_range(0..=index) as i32)));
+        } else if index + 1 < broken && rng.gen_ratio(1, 6) {
+            code.push(OpCode::Jmp(2));
+            code.push(OpCode::Acc(rng.gen_range(-50..=50)));
+        } else if rng.gen_ratio(1, 4) {
+            code.push(OpCode::Nop(0));
+        } else {
+            code.push(OpCode::Acc(rng.gen_range(-50..=50)));
+        }
+    }
+
+    let input = code
+        .iter()
+        .map(|op| match op {
+            OpCode::Nop(value) => format!("nop {:+}\n", value),
+            OpCode::Acc(value) => format!("acc {:+}\n", value),
+            OpCode::Jmp(value) => format!("jmp {:+}\n", value),
+        })
+        .collect::<String>();
+    let code = aoc08::parse(&input)?;
+
+    let (accumulator, terminated) = execute(&code);
+    ensure!(!terminated, "The program should loop");
+    let mut fixed = code;
+    fixed[broken] = OpCode::Nop(0);
+    let (repaired, terminated) = execute(&fixed);
+    ensure!(terminated, "The repaired program should terminate");
+
+    Ok(Generated {
+        input,
+        part1: Some(accumulator.to_string()),
+        part2: Some(repaired.to_string()),
+    })
+}
+
+/// Runs until an instruction repeats or the program ends, and tells whether
+/// it ended
+fn execute(code: &[OpCode]) -> (i32, bool) {
+    let mut visited = vec![false; code.len()];
+    let (mut pc, mut accumulator) = (0i64, 0);
+
+    while pc >= 0 && pc < code.len() as i64 {
+        if std::mem::replace(&mut visited[pc as usize], true) {
+            return (accumulator, false);
+        }
+        match code[pc as usize] {
+            OpCode::Nop(_) => pc += 1,
+            OpCode::Acc(value) => {
+                accumulator += value;
+                pc += 1;
+            }
+            OpCode::Jmp(value) => pc += value as i64,
+        }
+    }
+
+    (accumulator, pc == code.len() as i64)
+}
+
+/// Every number is the smallest of the last 25 plus another different one,
+/// which keeps the growth slow enough to fit in an i64. The last number is
+/// the sum of a few of the first ones, and smaller than any pair before it.
+fn xmas(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    const PREAMBLE: usize = 25;
+    let size = size.clamp(PREAMBLE + 2, 1000);
+
+    let mut numbers = (1..=100).collect::<Vec<i64>>();
+    numbers.shuffle(rng);
+    numbers.truncate(PREAMBLE);
+
+    while numbers.len() < size - 1 {
+        let mut window = numbers[numbers.len() - PREAMBLE..].to_vec();
+        window.sort_unstable();
+        window.dedup();
+        let pick = rng.gen_range(1..window.len());
+        let next = window[0]
+            .checked_add(window[pick])
+            .ok_or_else(|| anyhow!("The numbers grew too large"))?;
+        numbers.push(next);
+    }
+
+    let window = &numbers[numbers.len() - PREAMBLE..];
+    let is_sum = |target: i64| {
+        window
+            .iter()
+            .any(|a| window.iter().any(|b| a != b && a + b == target))
+    };
+    let run = (2..=PREAMBLE)
+        .find(|len| !is_sum(numbers[..*len].iter().sum()))
+        .ok_or_else(|| anyhow!("Could not plant an invalid number"))?;
+    let invalid = numbers[..run].iter().sum::<i64>();
+    // The numbers are positive, so no run from the start other than this one
+    // adds up to the invalid number, and the solution checks those first
+    let weakness =
+        numbers[..run].iter().min().unwrap_or(&0) + numbers[..run].iter().max().unwrap_or(&0);
+    numbers.push(invalid);
+
+    let input = lines(&numbers);
+    for event in aoc09::xmas::validate(input.as_bytes(), PREAMBLE) {
+        event?;
+    }
+
+    Ok(Generated {
+        input,
+        part1: Some(invalid.to_string()),
+        part2: Some(weakness.to_string()),
+    })
+}
+
+/// Adapters that step 1, 2 or 3 jolts up from the outlet, shuffled. The
+/// arrangements are counted along the way, and a step of 3, which does not
+/// add any, is taken whenever a smaller one would overflow the count.
+fn adapters(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    // The last few joltages with the number of ways to reach them
+    let mut reachable = vec![(0u64, 1u128)];
+    let mut steps = HashMap::new();
+    let mut adapters = vec![];
+
+    for _ in 0..size.max(1) {
+        let (last, _) = reachable[reachable.len() - 1];
+        let ways = |joltage: u64| {
+            reachable
+                .iter()
+                .filter(|(from, _)| joltage - from <= 3)
+                .try_fold(0u128, |sum, (_, ways)| sum.checked_add(*ways))
+        };
+
+        let step = rng.gen_range(1..=3);
+        let (step, count) = match ways(last + step) {
+            Some(count) => (step, count),
+            None => (3, ways(last + 3).unwrap()),
+        };
+        *steps.entry(step).or_insert(0usize) += 1;
+        adapters.push(last + step);
+        reachable.push((last + step, count));
+        reachable.retain(|(joltage, _)| last + step - joltage < 3);
+    }
+    let arrangements = reachable[reachable.len() - 1].1;
+    adapters.shuffle(rng);
+
+    let input = lines(&adapters);
+    aoc10::parse(&input, Default::default())?;
+
+    // The device is always 3 above the last adapter
+    let part1 = steps.get(&1).unwrap_or(&0) * (steps.get(&3).unwrap_or(&0) + 1);
+
+    Ok(Generated {
+        input,
+        part1: Some(part1.to_string()),
+        part2: Some(arrangements.to_string()),
+    })
+}
+
+/// Some seats flip back and forth forever. Those are turned into floor until
+/// the layout settles under both rules.
+fn seat_layout(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let mut seats = aoc11::parse(&grid(rng, size.max(1), 95, &[('L', 4), ('.', 1)]))?;
+
+    for _ in 0..100 {
+        let answers = (settle(&seats, false, 4), settle(&seats, true, 5));
+        match answers {
+            (Ok(part1), Ok(part2)) => {
+                let input = seats
+                    .iter()
+                    .map(|row| {
+                        let row = row.iter().map(|seat| match seat {
+                            State::Floor => '.',
+                            _ => 'L',
+                        });
+                        row.chain(Some('\n')).collect::<String>()
+                    })
+                    .collect::<String>();
+                aoc11::parse(&input)?;
+
+                return Ok(Generated {
+                    input,
+                    part1: Some(part1.to_string()),
+                    part2: Some(part2.to_string()),
+                });
+            }
+            (Err(flipping), _) | (_, Err(flipping)) => {
+                for (x, y) in flipping {
+                    seats[y][x] = State::Floor;
+                }
+            }
+        }
+    }
+
+    bail!("Could not find a seat layout that settles")
+}
+
+/// The number of occupied seats once nothing changes, or the seats that
+/// keep flipping. With `sight`, a seat sees the first seat in each direction
+/// instead of just its neighbours.
+fn settle(seats: &[Vec<State>], sight: bool, crowded: usize) -> Result<usize, Vec<(usize, usize)>> {
+    const DIRECTIONS: [(i64, i64); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    let (height, width) = (seats.len() as i64, seats[0].len() as i64);
+    let mut previous = vec![];
+    let mut state = seats.to_vec();
+
+    loop {
+        let occupied = |x: i64, y: i64| {
+            DIRECTIONS
+                .iter()
+                .filter(|(dx, dy)| {
+                    let (mut x, mut y) = (x + dx, y + dy);
+                    while x >= 0 && y >= 0 && x < width && y < height {
+                        match state[y as usize][x as usize] {
+                            State::Occupied => return true,
+                            State::Empty => return false,
+                            State::Floor if !sight => return false,
+                            State::Floor => {}
+                        }
+                        x += dx;
+                        y += dy;
+                    }
+                    false
+                })
+                .count()
+        };
+
+        let next = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| match state[y as usize][x as usize] {
+                        State::Empty if occupied(x, y) == 0 => State::Occupied,
+                        State::Occupied if occupied(x, y) >= crowded => State::Empty,
+                        seat => seat,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if next == state {
+            return Ok(state
+                .iter()
+                .flatten()
+                .filter(|seat| **seat == State::Occupied)
+                .count());
+        }
+        if next == previous {
+            let flipping = (0..seats.len())
+                .flat_map(|y| (0..seats[0].len()).map(move |x| (x, y)))
+                .filter(|(x, y)| next[*y][*x] != state[*y][*x])
+                .collect();
+            return Err(flipping);
+        }
+        previous = std::mem::replace(&mut state, next);
+    }
+}
+
+fn navigation(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let instructions = (0..size.max(1))
+        .map(|_| {
+            let distance = rng.gen_range(1..=100);
+            let degrees = 90 * rng.gen_range(1..=3);
+            match rng.gen_range(0..7) {
+                0 => Instruction::North(distance),
+                1 => Instruction::South(distance),
+                2 => Instruction::East(distance),
+                3 => Instruction::West(distance),
+                4 => Instruction::TurnLeft(degrees),
+                5 => Instruction::TurnRight(degrees),
+                _ => Instruction::MoveForward(distance),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let input = lines(&instructions);
+    ensure!(
+        aoc12::navigation::parse(&input)? == instructions,
+        "The instructions did not round trip"
+    );
+
+    Ok(Generated::unsolved(input))
+}
+
+/// Bus ids are distinct primes, so they are pairwise coprime and the busses
+/// always line up. Their product stays below 10^18 to fit the answer in a u64.
+fn bus_schedule(rng: &mut StdRng, size: usize) -> Result<Generated> {
+    let mut primes = (11..1000u64)
+        .filter(|n| (2..*n).take_while(|d| d * d <= *n).all(|d| n % d != 0))
+        .collect::<Vec<_>>();
+    primes.shuffle(rng);
+
+    let size = size.max(1);
+    let mut product = 1u64;
+    let mut ids = vec![];
+    for prime in primes {
+        match product.checked_mul(prime) {
+            Some(next) if ids.len() < size && next < 1_000_000_000_000_000_000 => product = next,
+            _ => break,
+        }
+        ids.push(prime);
+    }
+
+    let mut slots = vec!["x".to_owned(); size];
+    slots[0] = ids[0].to_string();
+    let mut offsets = (1..size).collect::<Vec<_>>();
+    offsets.shuffle(rng);
+    for (offset, id) in offsets.into_iter().zip(&ids[1..]) {
+        slots[offset] = id.to_string();
+    }
+
+    let input = format!(
+        "{}\n{}\n",
+        rng.gen_range(1_000_000..=1_010_000),
+        slots.join(",")
+    );
+    let schedule = input.parse::<aoc13::schedule::Schedule>()?;
+    let (id, wait) = schedule
+        .busses
+        .iter()
+        .map(|bus| (bus.id, (bus.id - schedule.earliest % bus.id) % bus.id))
+        .min_by_key(|(_, wait)| *wait)
+        .ok_or_else(|| anyhow!("The schedule has no busses"))?;
+
+    let congruences = schedule
+        .busses
+        .iter()
+        .map(|bus| (-(bus.offset as i128), bus.id as i128))
+        .collect::<Vec<_>>();
+    let (timestamp, _) = crt(&congruences)?;
+
+    Ok(Generated {
+        input,
+        part1: Some((id * wait).to_string()),
+        part2: Some(timestamp.to_string()),
+    })
+}
+
+/// One item per line
+fn lines<T: std::fmt::Display>(items: &[T]) -> String {
+    items.iter().map(|item| format!("{}\n", item)).collect()
+}
+
+/// `height` rows of `width` cells, picked with the given weights
+fn grid(rng: &mut StdRng, height: usize, width: usize, cells: &[(char, u32)]) -> String {
+    let total = cells.iter().map(|(_, weight)| weight).sum::<u32>();
+    let mut input = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            let mut roll = rng.gen_range(0..total);
+            for (cell, weight) in cells {
+                if roll < *weight {
+                    input.push(*cell);
+                    break;
+                }
+                roll -= weight;
+            }
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_known_answers_match_the_solutions() {
+        for day in days::all() {
+            let generator = match generator(day.number) {
+                Some(generator) => generator,
+                None => continue,
+            };
+
+            for seed in 0..3 {
+                let generated = generator(&mut StdRng::seed_from_u64(seed), 50).unwrap();
+                let parsed = (day.parse)(&generated.input).unwrap();
+                if let Some(answer) = generated.part1 {
+                    let solved = (day.part1)(&parsed).unwrap();
+                    assert_eq!(solved, answer, "day {} part1 seed {}", day.number, seed);
+                }
+                if let Some(answer) = generated.part2 {
+                    let solved = (day.part2)(&parsed).unwrap();
+                    assert_eq!(solved, answer, "day {} part2 seed {}", day.number, seed);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_adapters_part1_beyond_i32() {
+        let generated = adapters(&mut StdRng::seed_from_u64(1), 150_000).unwrap();
+        let chain = aoc10::parse(&generated.input, Default::default()).unwrap();
+
+        let part1 = aoc10::part1(&chain).unwrap();
+        assert!(part1 > i32::MAX as usize);
+        assert_eq!(generated.part1, Some(part1.to_string()));
+    }
+
+    #[test]
+    fn test_seeded() {
+        let generate = |seed| {
+            bag_rules(&mut StdRng::seed_from_u64(seed), 20)
+                .unwrap()
+                .input
+        };
+        assert_eq!(generate(7), generate(7));
+        assert_ne!(generate(7), generate(8));
+    }
+}
@@ -0,0 +1,184 @@
+mod days;
+mod gen;
+mod new_day;
+mod report;
+mod verify;
+mod watch;
+
+use std::{env, path::PathBuf};
+
+use anyhow::{anyhow, bail, Result};
+use rand::{rngs::StdRng, SeedableRng};
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+
+use crate::{days::Day, report::Format};
+
+/// The workspace root, where every day has its own directory
+fn root() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// A pool with `jobs` threads, or one per CPU for 0. Timings are only
+/// accurate with a single job, as parts then never share the CPU.
+fn pool(jobs: usize) -> Result<ThreadPool> {
+    Ok(ThreadPoolBuilder::new().num_threads(jobs).build()?)
+}
+
+/// Solves every day in `days` with its `input.txt`, printed in order. Days
+/// and parts run one at a time to keep the timings accurate, unless more
+/// jobs are asked for.
+fn run(days: &[Day], mut args: impl Iterator<Item = String>) -> Result<()> {
+    let (mut format, mut jobs) = (Format::Text, 1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing format"))?
+                    .parse()?
+            }
+            "--jobs" => {
+                jobs = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing jobs"))?
+                    .parse()?
+            }
+            _ => bail!("Unknown option {:?}", arg),
+        }
+    }
+
+    let root = root();
+    let reports = pool(jobs)?.install(|| {
+        days.par_iter()
+            .map(|day| report::solve(&root, day))
+            .collect::<Vec<_>>()
+    });
+    for reports in &reports {
+        report::print(reports, format)?;
+    }
+
+    Ok(())
+}
+
+/// Checks every day in `days` against its known answers, and fails if any
+/// answer is wrong
+fn verify(days: &[Day], mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut jobs = 0;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--jobs" => {
+                jobs = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing jobs"))?
+                    .parse()?
+            }
+            _ => bail!("Unknown option {:?}", arg),
+        }
+    }
+
+    let root = root();
+    let checks = pool(jobs)?.install(|| {
+        days.par_iter()
+            .map(|day| Ok(verify::verify(day, &verify::fixtures(&root, day)?)))
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let failed = checks
+        .iter()
+        .map(|checks| verify::print(checks))
+        .sum::<usize>();
+    let total = checks.iter().map(Vec::len).sum::<usize>();
+    if failed > 0 {
+        bail!("{} of {} checks failed", failed, total);
+    }
+
+    Ok(())
+}
+
+fn find(number: &str) -> Result<Day> {
+    let number = number.parse::<u32>()?;
+    days::all()
+        .into_iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| anyhow!("Day {} is not registered", number))
+}
+
+/// Prints a generated input, and with `--answers` the answers on stderr.
+/// Answers not known from the generator are solved with the day itself.
+fn gen(day: &Day, mut args: impl Iterator<Item = String>) -> Result<()> {
+    let (mut size, mut seed, mut answers) = (100, 0, false);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                size = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing size"))?
+                    .parse()?
+            }
+            "--seed" => {
+                seed = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing seed"))?
+                    .parse()?
+            }
+            "--answers" => answers = true,
+            _ => bail!("Unknown option {:?}", arg),
+        }
+    }
+
+    let generator =
+        gen::generator(day.number).ok_or_else(|| anyhow!("Day {} has no generator", day.number))?;
+    let generated = generator(&mut StdRng::seed_from_u64(seed), size)?;
+    print!("{}", generated.input);
+
+    if answers {
+        let parsed = (day.parse)(&generated.input)?;
+        let part1 = match generated.part1 {
+            Some(answer) => answer,
+            None => (day.part1)(&parsed)?,
+        };
+        let part2 = match generated.part2 {
+            Some(answer) => answer,
+            None => (day.part2)(&parsed)?,
+        };
+        eprintln!("part1: {}", part1);
+        eprintln!("part2: {}", part2);
+    }
+
+    Ok(())
+}
+
+const USAGE: &str =
+    "Usage: aoc run DAY|all [--format text|json] [--jobs N] | aoc verify DAY|all [--jobs N] | aoc watch DAY | aoc new-day DAY | aoc gen DAY [--size N] [--seed N] [--answers]";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+
+    match args.next().as_deref() {
+        Some("run") => match args.next().as_deref() {
+            Some("all") => run(&days::all(), args)?,
+            Some(number) => run(&[find(number)?], args)?,
+            None => bail!("Missing day"),
+        },
+        Some("verify") => match args.next().as_deref() {
+            Some("all") => verify(&days::all(), args)?,
+            Some(number) => verify(&[find(number)?], args)?,
+            None => bail!("Missing day"),
+        },
+        Some("watch") => {
+            let number = args.next().ok_or_else(|| anyhow!("Missing day"))?;
+            watch::watch(&root(), &find(&number)?)?;
+        }
+        Some("new-day") => {
+            let number = args.next().ok_or_else(|| anyhow!("Missing day"))?;
+            new_day::create(&root(), number.parse()?)?;
+        }
+        Some("gen") => {
+            let number = args.next().ok_or_else(|| anyhow!("Missing day"))?;
+            gen(&find(&number)?, args)?;
+        }
+        _ => bail!(USAGE),
+    }
+
+    Ok(())
+}
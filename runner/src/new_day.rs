@@ -0,0 +1,142 @@
+use std::{
+    env,
+    fs::{self, read_to_string},
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+
+/// The line every generated registration goes above
+const MARKER: &str = "# new-day adds days above this line";
+
+/// Creates `aocNN` from the template with an empty example fixture, registers
+/// it in the workspace and the runner, and fetches the input
+pub fn create(root: &Path, number: u32) -> Result<()> {
+    ensure!((1..=25).contains(&number), "Day {} is not in 1-25", number);
+
+    let name = format!("aoc{:02}", number);
+    let dir = root.join(&name);
+    ensure!(!dir.exists(), "{} already exists", name);
+
+    fs::create_dir_all(dir.join("src"))?;
+    for file in &["Cargo.toml", "build.rs", "src/lib.rs", "src/main.rs"] {
+        let template = read_to_string(root.join("template").join(file))?;
+        let generated = template
+            .replace("name = \"template\"", &format!("name = \"{}\"", name))
+            .replace("template::", &format!("{}::", name));
+        fs::write(dir.join(file), generated)?;
+    }
+
+    let examples = root.join("examples").join(format!("day{:02}", number));
+    fs::create_dir_all(&examples)?;
+    fs::write(examples.join("example.txt"), "")?;
+    fs::write(examples.join("example.expected"), "# part1: \n# part2: \n")?;
+
+    register(&root.join("Cargo.toml"), &format!("\"{}\",", name), MARKER)?;
+    register(
+        &root.join("runner/Cargo.toml"),
+        &format!("{} = {{path=\"../{}\"}}", name, name),
+        MARKER,
+    )?;
+    register(
+        &root.join("runner/src/days.rs"),
+        &format!("Day::new::<{}::Day>({}),", name, number),
+        &MARKER.replacen('#', "//", 1),
+    )?;
+
+    fetch_input(number, &dir.join("input.txt"))?;
+    println!("Created {}", name);
+
+    Ok(())
+}
+
+/// Inserts `line` above `marker`, with the same indentation
+fn register(path: &Path, line: &str, marker: &str) -> Result<()> {
+    let contents = read_to_string(path)?;
+    let start = contents
+        .find(marker)
+        .ok_or_else(|| anyhow!("{} has no {:?} line", path.display(), marker))?;
+    let indent_start = contents[..start].rfind('\n').map_or(0, |index| index + 1);
+    let indent = &contents[indent_start..start];
+
+    let mut updated = contents[..indent_start].to_owned();
+    updated.push_str(indent);
+    updated.push_str(line);
+    updated.push('\n');
+    updated.push_str(&contents[indent_start..]);
+
+    Ok(fs::write(path, updated)?)
+}
+
+/// Downloads the input with the session cookie in `AOC_SESSION`, or leaves an
+/// empty placeholder without it
+fn fetch_input(number: u32, path: &Path) -> Result<()> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => {
+            println!("AOC_SESSION is not set, leaving {} empty", path.display());
+            return Ok(fs::write(path, "")?);
+        }
+    };
+
+    // The cookie goes through stdin, as arguments are visible to every user
+    let config = cookie_config(&session)?;
+    let mut curl = Command::new("curl")
+        .arg("--fail")
+        .arg("--silent")
+        .arg("--show-error")
+        .arg("--config")
+        .arg("-")
+        .arg(format!(
+            "https://adventofcode.com/2020/day/{}/input",
+            number
+        ))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    curl.stdin
+        .take()
+        .ok_or_else(|| anyhow!("Could not write to curl"))?
+        .write_all(config.as_bytes())?;
+    let output = curl.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "Could not fetch the input: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(fs::write(path, output.stdout)?)
+}
+
+/// A curl config line that sends `session` as the session cookie
+fn cookie_config(session: &str) -> Result<String> {
+    ensure!(
+        !session.contains(|ch: char| ch.is_control()),
+        "AOC_SESSION contains control characters"
+    );
+    let session = session.replace('\\', "\\\\").replace('"', "\\\"");
+
+    Ok(format!("cookie = \"session={}\"\n", session))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cookie_config() {
+        assert_eq!(
+            cookie_config("53616c7465").unwrap(),
+            "cookie = \"session=53616c7465\"\n"
+        );
+        assert_eq!(
+            cookie_config(r#"a"b\c"#).unwrap(),
+            r#"cookie = "session=a\"b\\c""#.to_owned() + "\n"
+        );
+        assert!(cookie_config("a\nurl = \"http://example.com\"").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use std::{fs::read_to_string, path::Path, str::FromStr, time::Instant};
+
+use anyhow::{bail, Error, Result};
+use serde::Serialize;
+
+use crate::days::{Day, Parsed};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line for every part
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(format: &str) -> Result<Self> {
+        match format {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("Unknown format {:?}, expected text or json", format),
+        }
+    }
+}
+
+/// The outcome of solving one part of a day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub day: u32,
+    pub part: u8,
+    pub answer: Option<String>,
+    #[serde(rename = "type")]
+    pub answer_type: &'static str,
+    /// Both parts share one parse, so they have the same parse time. `None`
+    /// if the input could not be read.
+    pub parse_ms: Option<f64>,
+    /// `None` if the part never ran, because the input could not be read or
+    /// parsed
+    pub solve_ms: Option<f64>,
+    pub error: Option<String>,
+}
+
+impl Report {
+    /// A report for a part that has not run yet
+    fn new(day: &Day, part: u8) -> Self {
+        Report {
+            day: day.number,
+            part,
+            answer: None,
+            answer_type: day.types[part as usize - 1],
+            parse_ms: None,
+            solve_ms: None,
+            error: None,
+        }
+    }
+}
+
+/// Solves both parts of `day` with its `input.txt` under `root`
+pub fn solve(root: &Path, day: &Day) -> [Report; 2] {
+    let path = root.join(day.name()).join("input.txt");
+    match read_to_string(&path) {
+        Ok(input) => solve_input(day, &input),
+        Err(err) => failed(day, format!("Could not read {}: {}", path.display(), err)),
+    }
+}
+
+/// Parses `input` once and solves both parts of `day` with it. The parts run
+/// in parallel on the current rayon pool.
+pub fn solve_input(day: &Day, input: &str) -> [Report; 2] {
+    let start = Instant::now();
+    let parsed = (day.parse)(input);
+    let parse_ms = Some(start.elapsed().as_secs_f64() * 1000.0);
+
+    let mut reports = match parsed {
+        Ok(parsed) => {
+            let (part1, part2) = rayon::join(
+                || solve_part(day, 1, &parsed),
+                || solve_part(day, 2, &parsed),
+            );
+            [part1, part2]
+        }
+        Err(err) => failed(day, format!("{:#}", err)),
+    };
+    for report in &mut reports {
+        report.parse_ms = parse_ms;
+    }
+
+    reports
+}
+
+/// Reports for both parts of `day` that never ran because of `error`
+fn failed(day: &Day, error: String) -> [Report; 2] {
+    let mut reports = [Report::new(day, 1), Report::new(day, 2)];
+    for report in &mut reports {
+        report.error = Some(error.clone());
+    }
+
+    reports
+}
+
+/// Solves one part of `day`, timing only the part itself
+fn solve_part(day: &Day, part: u8, parsed: &Parsed) -> Report {
+    let mut report = Report::new(day, part);
+    let solve = if part == 1 { day.part1 } else { day.part2 };
+
+    let start = Instant::now();
+    let answer = solve(parsed);
+    report.solve_ms = Some(start.elapsed().as_secs_f64() * 1000.0);
+
+    match answer {
+        Ok(answer) => report.answer = Some(answer),
+        Err(err) => report.error = Some(format!("{:#}", err)),
+    }
+
+    report
+}
+
+/// Prints the reports for one day. In the text format the first error is
+/// returned instead, as there is nowhere to put it.
+pub fn print(reports: &[Report], format: Format) -> Result<()> {
+    match format {
+        Format::Text => {
+            if let Some(report) = reports.first() {
+                println!("day {}", report.day);
+            }
+            for report in reports {
+                match (&report.answer, &report.error) {
+                    (Some(answer), _) => println!("  part{}: {}", report.part, answer),
+                    (_, error) => bail!("{}", error.as_deref().unwrap_or("No answer")),
+                }
+            }
+        }
+        Format::Json => {
+            for report in reports {
+                println!("{}", serde_json::to_string(report)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let report = Report {
+            day: 7,
+            part: 2,
+            answer: Some("32".to_owned()),
+            answer_type: "u32",
+            parse_ms: None,
+            solve_ms: Some(1.5),
+            error: None,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            r#"{"day":7,"part":2,"answer":"32","type":"u32","parse_ms":null,"solve_ms":1.5,"error":null}"#
+        );
+    }
+
+    #[test]
+    fn test_parse_once() {
+        let day = crate::days::all().remove(0);
+        let reports = solve_input(&day, "1721\n979\n366\n299\n675\n1456\n");
+
+        assert_eq!(reports[0].answer.as_deref(), Some("514579"));
+        assert_eq!(reports[1].answer.as_deref(), Some("241861950"));
+        assert!(reports[0].parse_ms.is_some());
+        assert_eq!(reports[0].parse_ms, reports[1].parse_ms);
+
+        let reports = solve_input(&day, "1721\nx\n");
+        assert!(reports[0].parse_ms.is_some());
+        assert_eq!(reports[0].solve_ms, None);
+        assert_eq!(reports[0].error, reports[1].error);
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let day = crate::days::all().remove(0);
+        let reports = solve(Path::new("/nonexistent"), &day);
+
+        assert_eq!(reports[1].part, 2);
+        assert_eq!(reports[1].answer_type, "i64");
+        assert_eq!(reports[1].parse_ms, None);
+        assert_eq!(reports[1].solve_ms, None);
+        assert!(reports[1]
+            .error
+            .as_deref()
+            .unwrap()
+            .starts_with("Could not read /nonexistent/aoc01/input.txt"));
+    }
+}
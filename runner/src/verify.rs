@@ -0,0 +1,160 @@
+//! Checks the answers of every day against the known ones: the example
+//! fixtures in `examples/dayNN`, and `input.txt` when an `input.expected` is
+//! next to it.
+
+use std::{
+    fs::{self, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Result};
+use rayon::prelude::*;
+use utils::examples::parse_expected;
+
+use crate::{
+    days::Day,
+    report::{self, Report},
+};
+
+/// An input with the answers it should give
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub expected: [Option<String>; 2],
+}
+
+/// The outcome of solving one part of a fixture
+pub struct Check {
+    pub fixture: String,
+    pub expected: String,
+    pub report: Report,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.report.answer.as_ref() == Some(&self.expected)
+    }
+}
+
+fn read(name: String, input: &Path) -> Result<Fixture> {
+    let expected = input.with_extension("expected");
+    let text =
+        read_to_string(&expected).map_err(|err| anyhow!("{}: {}", expected.display(), err))?;
+
+    Ok(Fixture {
+        name,
+        input: read_to_string(input).map_err(|err| anyhow!("{}: {}", input.display(), err))?,
+        expected: parse_expected(&text)
+            .map_err(|err| anyhow!("{}: {}", expected.display(), err))?,
+    })
+}
+
+/// The example fixtures of `day` sorted by name, followed by its input if
+/// the answers for it are known
+pub fn fixtures(root: &Path, day: &Day) -> Result<Vec<Fixture>> {
+    let dir = root.join("examples").join(format!("day{:02}", day.number));
+    let mut inputs = match fs::read_dir(&dir) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<PathBuf>, _>>()?,
+        Err(_) => vec![],
+    };
+    inputs.retain(|path| path.extension().is_some_and(|extension| extension == "txt"));
+    inputs.sort();
+
+    let mut fixtures = inputs
+        .iter()
+        .map(|input| {
+            let name = input.file_stem().unwrap_or_default().to_string_lossy();
+            read(name.into_owned(), input)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let input = root.join(day.name()).join("input.txt");
+    if input.with_extension("expected").exists() {
+        fixtures.push(read("input".to_owned(), &input)?);
+    }
+
+    Ok(fixtures)
+}
+
+/// Solves every fixture in parallel on the current rayon pool, and checks
+/// the parts with a known answer. The checks keep the order of the fixtures.
+pub fn verify(day: &Day, fixtures: &[Fixture]) -> Vec<Check> {
+    let reports = fixtures
+        .par_iter()
+        .map(|fixture| report::solve_input(day, &fixture.input))
+        .collect::<Vec<_>>();
+
+    fixtures
+        .iter()
+        .zip(reports)
+        .flat_map(|(fixture, reports)| {
+            fixture
+                .expected
+                .iter()
+                .zip(reports)
+                .filter_map(move |(expected, report)| {
+                    Some(Check {
+                        fixture: fixture.name.clone(),
+                        expected: expected.clone()?,
+                        report,
+                    })
+                })
+        })
+        .collect()
+}
+
+/// Prints one line per check, and returns the number of failed checks
+pub fn print(checks: &[Check]) -> usize {
+    for check in checks {
+        let report = &check.report;
+        let outcome = match (&report.answer, &report.error) {
+            _ if check.passed() => "ok".to_owned(),
+            (Some(answer), _) => format!("expected {}, got {}", check.expected, answer),
+            (_, error) => format!(
+                "expected {}, failed: {}",
+                check.expected,
+                error.as_deref().unwrap_or("No answer")
+            ),
+        };
+        println!(
+            "day {} {} part{}: {}",
+            report.day, check.fixture, report.part, outcome
+        );
+    }
+
+    checks.iter().filter(|check| !check.passed()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_examples() {
+        let root = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+        let day = crate::days::all().remove(6);
+        let fixtures = fixtures(&root, &day).unwrap();
+        assert_eq!(fixtures[0].name, "example");
+
+        let checks = verify(&day, &fixtures);
+        assert!(!checks.is_empty());
+        assert!(checks.iter().all(Check::passed));
+    }
+
+    #[test]
+    fn test_wrong_answer() {
+        let day = crate::days::all().remove(0);
+        let fixtures = [Fixture {
+            name: "wrong".to_owned(),
+            input: "1010\n1010\n".to_owned(),
+            expected: [Some("1".to_owned()), None],
+        }];
+
+        let checks = verify(&day, &fixtures);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].report.answer.as_deref(), Some("1020100"));
+        assert!(!checks[0].passed());
+    }
+}
@@ -0,0 +1,232 @@
+//! `aoc watch DAY` rebuilds a day whenever its source, example fixtures or
+//! input change, runs its tests and both parts, and shows how the answers
+//! changed since the last run. Changes are found by polling modification
+//! times, which is plenty for a handful of files.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Result;
+
+use crate::days::Day;
+
+const INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification time of every file being watched
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// The answers printed by a day, by part
+type Answers = BTreeMap<String, String>;
+
+/// The files and directories that affect the answers of `day`, including
+/// the shared code in `utils`
+fn paths(root: &Path, day: &Day) -> Vec<PathBuf> {
+    let dir = root.join(day.name());
+    vec![
+        dir.join("src"),
+        dir.join("build.rs"),
+        dir.join("Cargo.toml"),
+        dir.join("input.txt"),
+        root.join("examples").join(format!("day{:02}", day.number)),
+        root.join("utils").join("src"),
+        root.join("Cargo.lock"),
+    ]
+}
+
+/// Every file under `paths`. Files that are missing are left out, so creating
+/// or removing one changes the snapshot too.
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    let mut pending = paths.to_vec();
+
+    while let Some(path) = pending.pop() {
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(_) => continue,
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            snapshot.insert(path, modified);
+        }
+    }
+
+    snapshot
+}
+
+/// The `partN: ANSWER` lines in the output of a day
+fn answers(output: &str) -> Answers {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (part, answer) = line.split_once(':')?;
+            if !part.starts_with("part") {
+                return None;
+            }
+            Some((part.to_owned(), answer.trim().to_owned()))
+        })
+        .collect()
+}
+
+/// One line per part, comparing the answers with the ones from the last run
+fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.get(part) {
+            None => format!("{}: {}", part, answer),
+            Some(before) if before == answer => format!("{}: {} (unchanged)", part, answer),
+            Some(before) => format!("{}: {} (was {})", part, answer, before),
+        })
+        .chain(
+            previous
+                .keys()
+                .filter(|part| !current.contains_key(*part))
+                .map(|part| format!("{}: gone", part)),
+        )
+        .collect()
+}
+
+/// The diff against the previous answers, and the answers to keep. When the
+/// day failed, the parts it did not print are not gone but kept as they were.
+fn update(previous: &Answers, output: &str, succeeded: bool) -> (Vec<String>, Answers) {
+    let mut current = answers(output);
+    if succeeded {
+        return (diff(previous, &current), current);
+    }
+
+    let printed = previous
+        .iter()
+        .filter(|(part, _)| current.contains_key(*part))
+        .map(|(part, answer)| (part.clone(), answer.clone()))
+        .collect();
+    let lines = diff(&printed, &current);
+    for (part, answer) in previous {
+        current
+            .entry(part.clone())
+            .or_insert_with(|| answer.clone());
+    }
+
+    (lines, current)
+}
+
+/// The cargo running us, or the one on the path
+fn cargo() -> Command {
+    Command::new(env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo")))
+}
+
+/// Runs the tests and both parts of `day`, and returns the answers to
+/// compare the next run with
+fn run_once(root: &Path, day: &Day, previous: &Answers) -> Result<Answers> {
+    let name = day.name();
+
+    println!("==> Testing {}", name);
+    let tests = cargo()
+        .args(["test", "--quiet", "--package", &name])
+        .current_dir(root)
+        .status()?;
+    if !tests.success() {
+        println!("==> Tests failed");
+    }
+
+    println!("==> Running {}", name);
+    let output = cargo()
+        .args(["run", "--quiet", "--release", "--package", &name])
+        .current_dir(root.join(&name))
+        .output()?;
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    let succeeded = output.status.success();
+    let (lines, current) = update(
+        previous,
+        &String::from_utf8_lossy(&output.stdout),
+        succeeded,
+    );
+    for line in lines {
+        println!("{}", line);
+    }
+    if !succeeded {
+        println!("==> {} failed", name);
+    }
+
+    Ok(current)
+}
+
+/// Runs `day` now and after every change, until interrupted
+pub fn watch(root: &Path, day: &Day) -> Result<()> {
+    let paths = paths(root, day);
+    let mut last = None;
+    let mut answers = Answers::new();
+
+    loop {
+        let current = snapshot(&paths);
+        if last.as_ref() != Some(&current) {
+            last = Some(current);
+            answers = run_once(root, day, &answers)?;
+            println!("==> Watching {} for changes", day.name());
+        }
+
+        sleep(INTERVAL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let previous = answers("part1: 5\npart2: 7\n");
+        let current = answers("Changed to nop\npart1: 5\npart2: 8\npart3: 1\n");
+
+        assert_eq!(
+            diff(&previous, &current),
+            vec!["part1: 5 (unchanged)", "part2: 8 (was 7)", "part3: 1"]
+        );
+        assert_eq!(
+            diff(&previous, &Answers::new()),
+            vec!["part1: gone", "part2: gone"]
+        );
+    }
+
+    #[test]
+    fn test_update_after_failure() {
+        let previous = answers("part1: 5\npart2: 7\n");
+
+        let (lines, current) = update(&previous, "part1: 6\n", false);
+        assert_eq!(lines, vec!["part1: 6 (was 5)"]);
+        assert_eq!(current, answers("part1: 6\npart2: 7\n"));
+
+        let (lines, current) = update(&previous, "part1: 6\n", true);
+        assert_eq!(lines, vec!["part1: 6 (was 5)", "part2: gone"]);
+        assert_eq!(current, answers("part1: 6\n"));
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        let paths = [dir.join("src"), dir.join("input.txt")];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.len(), 1);
+
+        fs::write(dir.join("input.txt"), "1\n").unwrap();
+        let after = snapshot(&paths);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(after.len(), 2);
+        assert_ne!(before, after);
+    }
+}
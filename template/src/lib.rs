@@ -0,0 +1,36 @@
+use anyhow::{bail, Result};
+use utils::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i64> {
+        part2(input)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(str::to_owned).collect())
+}
+
+pub fn part1(_lines: &[String]) -> Result<i64> {
+    bail!("Not solved yet")
+}
+
+pub fn part2(_lines: &[String]) -> Result<i64> {
+    bail!("Not solved yet")
+}
+
+utils::example_tests!();
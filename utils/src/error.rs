@@ -0,0 +1,97 @@
+//! The ways a solution can fail. Parts return `anyhow::Result`, so an
+//! `AocError` can be returned with `?` or `bail!` and found again with
+//! `downcast_ref`.
+
+use std::fmt;
+
+use crate::{number_theory::CrtError, parse::ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The input does not have the expected format
+    Parse(ParseError),
+    /// The input is well formed, but has no answer, like a program without a
+    /// repairable instruction
+    NoSolution(String),
+    /// The input is well formed, but breaks an assumption of the puzzle, like
+    /// a bag rule that mentions an unknown bag
+    InvalidInput(String),
+    /// The answer or a value on the way there does not fit in its type
+    Overflow(String),
+}
+
+impl AocError {
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        AocError::NoSolution(message.into())
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        AocError::InvalidInput(message.into())
+    }
+
+    pub fn overflow(message: impl Into<String>) -> Self {
+        AocError::Overflow(message.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse(err) => write!(f, "{}", err),
+            AocError::NoSolution(message) => write!(f, "No solution: {}", message),
+            AocError::InvalidInput(message) => write!(f, "Invalid input: {}", message),
+            AocError::Overflow(message) => write!(f, "Overflow: {}", message),
+        }
+    }
+}
+
+/// The parse error is not a `source`, as it is already part of the message
+impl std::error::Error for AocError {}
+
+impl From<ParseError> for AocError {
+    fn from(err: ParseError) -> Self {
+        AocError::Parse(err)
+    }
+}
+
+impl From<CrtError> for AocError {
+    fn from(err: CrtError) -> Self {
+        match err {
+            CrtError::NoSolution => AocError::no_solution(err.to_string()),
+            CrtError::InvalidModulus(_) => AocError::invalid_input(err.to_string()),
+            CrtError::Overflow => AocError::overflow(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Parser;
+
+    #[test]
+    fn test_display() {
+        let err = AocError::from(Parser::new("x").integer::<u8>().unwrap_err());
+        assert_eq!(
+            err.to_string(),
+            "Line 1, column 1: expected an integer, found \"x\""
+        );
+        assert_eq!(
+            format!("{:#}", anyhow::Error::from(err)),
+            "Line 1, column 1: expected an integer, found \"x\""
+        );
+        assert_eq!(
+            AocError::no_solution("all numbers are valid").to_string(),
+            "No solution: all numbers are valid"
+        );
+    }
+
+    #[test]
+    fn test_downcast() {
+        let err = anyhow::Error::from(AocError::overflow("too many bags"));
+        assert_eq!(
+            err.downcast_ref::<AocError>(),
+            Some(&AocError::overflow("too many bags"))
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Example fixtures live in `examples/dayNN/` at the workspace root. Every
+//! `NAME.txt` there is an input, and `NAME.expected` next to it holds the
+//! answers as `part1: ANSWER` and `part2: ANSWER` lines. A part without an
+//! answer gets no test, and lines starting with `#` are ignored.
+//!
+//! A day opts in with a build script calling [`generate`] and
+//! `utils::example_tests!()` in its lib.rs.
+
+use std::{
+    env,
+    fmt::Write,
+    fs::{self, read_to_string},
+    path::{Path, PathBuf},
+};
+
+use crate::Solution;
+
+/// Includes the tests written by [`generate`]
+#[macro_export]
+macro_rules! example_tests {
+    () => {
+        #[cfg(test)]
+        mod examples {
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+/// Parses a `.expected` file into the answers for part 1 and part 2
+pub fn parse_expected(text: &str) -> Result<[Option<String>; 2], String> {
+    let mut answers = [None, None];
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = || {
+            format!(
+                "Line {}: expected \"partN: ANSWER\", got {:?}",
+                index + 1,
+                line
+            )
+        };
+        let (part, answer) = line.split_once(':').ok_or_else(error)?;
+        let slot = match part.trim() {
+            "part1" => &mut answers[0],
+            "part2" => &mut answers[1],
+            _ => return Err(error()),
+        };
+        *slot = Some(answer.trim().to_owned());
+    }
+
+    Ok(answers)
+}
+
+/// Turns a fixture name into something usable in a test name
+fn identifier(name: &str) -> String {
+    let identifier = name
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    if identifier.starts_with(|ch: char| ch.is_ascii_digit()) {
+        format!("example_{}", identifier)
+    } else {
+        identifier
+    }
+}
+
+/// The Rust source for one test per fixture per part in `dir`, sorted by name
+fn tests(dir: &Path) -> Result<String, String> {
+    let mut fixtures = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| err.to_string())?,
+        Err(_) => vec![],
+    };
+    fixtures.retain(|path| path.extension().is_some_and(|extension| extension == "txt"));
+    fixtures.sort();
+
+    let mut source = String::new();
+    for input in fixtures {
+        let name = input.file_stem().unwrap_or_default().to_string_lossy();
+        let expected = input.with_extension("expected");
+        let text =
+            read_to_string(&expected).map_err(|err| format!("{}: {}", expected.display(), err))?;
+        let answers =
+            parse_expected(&text).map_err(|err| format!("{}: {}", expected.display(), err))?;
+
+        for (part, answer) in (1..=2).zip(answers.iter()) {
+            if let Some(answer) = answer {
+                writeln!(
+                    source,
+                    "#[test]\nfn {}_part{}() {{\n    utils::examples::check::<crate::Day>({}, include_str!({:?}), {:?});\n}}\n",
+                    identifier(&name),
+                    part,
+                    part,
+                    input.display().to_string(),
+                    answer
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    Ok(source)
+}
+
+/// Writes `$OUT_DIR/examples.rs` for the package running the build script.
+/// The fixtures are found from the package name, so `aoc07` uses
+/// `examples/day07`.
+pub fn generate() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let package = env::var("CARGO_PKG_NAME").unwrap();
+    let root = manifest_dir.join("../examples");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let source = match package.strip_prefix("aoc") {
+        Some(number) => {
+            tests(&root.join(format!("day{}", number))).unwrap_or_else(|err| panic!("{}", err))
+        }
+        None => String::new(),
+    };
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("examples.rs"), source).unwrap();
+}
+
+/// Parses an example, runs one part on it and compares the answer as text
+pub fn check<S: Solution>(part: u8, input: &str, expected: &str) {
+    let input = match S::parse(input) {
+        Ok(input) => input,
+        Err(err) => panic!("parsing failed: {:#}", err),
+    };
+    let answer = match part {
+        1 => S::part1(&input).map(|answer| answer.to_string()),
+        _ => S::part2(&input).map(|answer| answer.to_string()),
+    };
+
+    match answer {
+        Ok(answer) => assert_eq!(answer, expected, "part{}", part),
+        Err(err) => panic!("part{} failed: {:#}", part, err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        assert_eq!(
+            parse_expected("part1: 7\n# part2: ?\n\n").unwrap(),
+            [Some("7".to_owned()), None]
+        );
+        assert_eq!(
+            parse_expected("part2:abc def\n").unwrap(),
+            [None, Some("abc def".to_owned())]
+        );
+        assert!(parse_expected("part3: 1").is_err());
+        assert!(parse_expected("part1 1").is_err());
+    }
+
+    #[test]
+    fn test_identifier() {
+        assert_eq!(identifier("example"), "example");
+        assert_eq!(identifier("Larger-Example 2"), "larger_example_2");
+        assert_eq!(identifier("2"), "example_2");
+    }
+}
@@ -0,0 +1,513 @@
+//! A directed graph as adjacency lists. Nodes are interned, so each distinct
+//! node gets one [`NodeId`], and the algorithms work on ids only.
+
+use std::{
+    borrow::Borrow,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt,
+    hash::Hash,
+    ops::Add,
+};
+
+/// The index of a node, in the order the nodes were added
+pub type NodeId = usize;
+
+/// Nodes where each one has an edge to the next, and the last one has an edge
+/// back to the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<NodeId>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// The graph has to be acyclic, but has this cycle
+    Cycle(Cycle),
+    /// The number of paths does not fit in an u128
+    Overflow,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::Cycle(cycle) => write!(f, "The graph has a cycle through {:?}", cycle.0),
+            GraphError::Overflow => write!(f, "The number of paths overflowed"),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// A cheapest path and what it costs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<W> {
+    pub cost: W,
+    /// From the start to the goal, both included
+    pub nodes: Vec<NodeId>,
+}
+
+/// A directed graph with a weight `W` on every edge
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = ()> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<N: Eq + Hash + Clone, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Graph {
+            nodes: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    /// On the path of the search, so reaching it again closes a cycle
+    Open,
+    Done,
+}
+
+impl<N: Eq + Hash + Clone, W> Graph<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of `node`, which is added if it is new
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        if let Some(id) = self.ids.get(&node) {
+            return *id;
+        }
+
+        let id = self.nodes.len();
+        self.ids.insert(node.clone(), id);
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// Adds an edge, and any of the nodes that are new
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn id<Q>(&self, node: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The outgoing edges of `id`, in the order they were added
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.edges[id]
+    }
+
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|(next, _)| *next)
+    }
+
+    /// The same graph with every edge pointing the other way. The ids stay the
+    /// same.
+    pub fn reversed(&self) -> Self
+    where
+        W: Clone,
+    {
+        let mut edges = vec![vec![]; self.len()];
+        for (from, outgoing) in self.edges.iter().enumerate() {
+            for (to, weight) in outgoing {
+                edges[*to].push((from, weight.clone()));
+            }
+        }
+
+        Graph {
+            nodes: self.nodes.clone(),
+            ids: self.ids.clone(),
+            edges,
+        }
+    }
+
+    /// Every node reachable from `start` in breadth-first order, starting with
+    /// `start`
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = vec![];
+        let mut queue = VecDeque::from(vec![start]);
+        seen[start] = true;
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for next in self.neighbours(node) {
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Every node reachable from `start` in depth-first preorder, starting with
+    /// `start`
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = vec![];
+        let mut stack = vec![start];
+
+        while let Some(node) = stack.pop() {
+            if seen[node] {
+                continue;
+            }
+            seen[node] = true;
+            order.push(node);
+            // Reversed, so the first edge is followed first
+            stack.extend(
+                self.edges[node]
+                    .iter()
+                    .rev()
+                    .map(|(next, _)| *next)
+                    .filter(|next| !seen[*next]),
+            );
+        }
+
+        order
+    }
+
+    /// Every node reachable from `start`, including `start`
+    pub fn reachable(&self, start: NodeId) -> HashSet<NodeId> {
+        self.bfs(start).into_iter().collect()
+    }
+
+    /// Every node reachable from `starts` with each node after all of the
+    /// nodes it has an edge to, or the first cycle found
+    fn postorder(&self, starts: impl IntoIterator<Item = NodeId>) -> Result<Vec<NodeId>, Cycle> {
+        let mut visits = vec![Visit::New; self.len()];
+        let mut order = vec![];
+
+        for start in starts {
+            if visits[start] != Visit::New {
+                continue;
+            }
+            visits[start] = Visit::Open;
+            // The path from `start`, with the next edge to follow for each node
+            let mut path = vec![(start, 0)];
+
+            while let Some(&(node, edge)) = path.last() {
+                let next = match self.edges[node].get(edge) {
+                    Some((next, _)) => *next,
+                    None => {
+                        visits[node] = Visit::Done;
+                        order.push(node);
+                        path.pop();
+                        continue;
+                    }
+                };
+                if let Some(last) = path.last_mut() {
+                    last.1 += 1;
+                }
+
+                match visits[next] {
+                    Visit::New => {
+                        visits[next] = Visit::Open;
+                        path.push((next, 0));
+                    }
+                    Visit::Open => {
+                        let at = path.iter().position(|(node, _)| *node == next);
+                        let cycle = path[at.unwrap_or(0)..].iter().map(|(node, _)| *node);
+                        return Err(Cycle(cycle.collect()));
+                    }
+                    Visit::Done => {}
+                }
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Any cycle in the graph
+    pub fn find_cycle(&self) -> Option<Cycle> {
+        self.postorder(0..self.len()).err()
+    }
+
+    /// Any cycle reachable from `start`
+    pub fn find_cycle_from(&self, start: NodeId) -> Option<Cycle> {
+        self.postorder(Some(start)).err()
+    }
+
+    /// Every node, each one before all of the nodes it has an edge to
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, Cycle> {
+        let mut order = self.postorder(0..self.len())?;
+        order.reverse();
+        Ok(order)
+    }
+
+    /// The number of paths from `from` to `to`, counting each path through the
+    /// part of the graph reachable from `from` once
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Result<u128, GraphError> {
+        let order = self.postorder(Some(from)).map_err(GraphError::Cycle)?;
+        let mut paths = vec![0u128; self.len()];
+        self.count_in_order(&order, to, &mut paths)?;
+
+        Ok(paths[from])
+    }
+
+    /// For every node, the number of paths from it to `to`. The whole graph has
+    /// to be acyclic.
+    pub fn count_paths_to(&self, to: NodeId) -> Result<Vec<u128>, GraphError> {
+        let order = self.postorder(0..self.len()).map_err(GraphError::Cycle)?;
+        let mut paths = vec![0u128; self.len()];
+        self.count_in_order(&order, to, &mut paths)?;
+
+        Ok(paths)
+    }
+
+    /// Fills in `paths` for the nodes in `order`, which has every node after
+    /// the nodes it has an edge to
+    fn count_in_order(
+        &self,
+        order: &[NodeId],
+        to: NodeId,
+        paths: &mut [u128],
+    ) -> Result<(), GraphError> {
+        for &node in order {
+            paths[node] = if node == to {
+                1
+            } else {
+                self.neighbours(node)
+                    .try_fold(0u128, |sum, next| sum.checked_add(paths[next]))
+                    .ok_or(GraphError::Overflow)?
+            };
+        }
+
+        Ok(())
+    }
+
+    /// The cheapest path from `from` to `to` with Dijkstra's algorithm
+    pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Option<Path<W>>
+    where
+        W: Copy + Ord + Default + Add<Output = W>,
+    {
+        self.a_star(from, to, |_| W::default())
+    }
+
+    /// The cheapest path from `from` to `to` with A*. The `heuristic` guesses
+    /// the cost from a node to `to`, and must never guess more than the cost
+    /// of an edge plus the guess from where it leads.
+    pub fn a_star(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        heuristic: impl Fn(NodeId) -> W,
+    ) -> Option<Path<W>>
+    where
+        W: Copy + Ord + Default + Add<Output = W>,
+    {
+        let mut costs = vec![None; self.len()];
+        let mut previous = vec![None; self.len()];
+        let mut done = vec![false; self.len()];
+        let mut queue = BinaryHeap::new();
+        costs[from] = Some(W::default());
+        queue.push(Reverse((heuristic(from), from)));
+
+        while let Some(Reverse((_, node))) = queue.pop() {
+            if done[node] {
+                continue;
+            }
+            done[node] = true;
+            let cost = costs[node]?;
+
+            if node == to {
+                let mut nodes = vec![to];
+                while let Some(before) = previous[*nodes.last()?] {
+                    nodes.push(before);
+                }
+                nodes.reverse();
+                return Some(Path { cost, nodes });
+            }
+
+            for (next, weight) in self.edges(node) {
+                let next_cost = cost + *weight;
+                if costs[*next].is_none_or(|known| next_cost < known) {
+                    costs[*next] = Some(next_cost);
+                    previous[*next] = Some(node);
+                    queue.push(Reverse((next_cost + heuristic(*next), *next)));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b -> d, a -> c -> d, d -> e, and f on its own
+    fn diamond() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for (from, to) in &[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("d", "e")] {
+            graph.add_edge(*from, *to, ());
+        }
+        graph.add_node("f");
+        graph
+    }
+
+    fn names(graph: &Graph<&'static str>, ids: &[NodeId]) -> Vec<&'static str> {
+        ids.iter().map(|id| *graph.node(*id)).collect()
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = diamond();
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.add_node("c"), graph.id("c").unwrap());
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.id("x"), None);
+        assert_eq!(*graph.node(graph.id("d").unwrap()), "d");
+    }
+
+    #[test]
+    fn test_traversals() {
+        let graph = diamond();
+        let a = graph.id("a").unwrap();
+
+        assert_eq!(names(&graph, &graph.bfs(a)), ["a", "b", "c", "d", "e"]);
+        assert_eq!(names(&graph, &graph.dfs(a)), ["a", "b", "d", "e", "c"]);
+
+        let reachable = graph.reversed().reachable(graph.id("d").unwrap());
+        let mut reachable = names(&graph, &reachable.into_iter().collect::<Vec<_>>());
+        reachable.sort_unstable();
+        assert_eq!(reachable, ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = diamond();
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(order.len(), graph.len());
+
+        let position = |name| order.iter().position(|id| *graph.node(*id) == name);
+        assert!(position("a") < position("b"));
+        assert!(position("b") < position("d"));
+        assert!(position("c") < position("d"));
+        assert!(position("d") < position("e"));
+    }
+
+    #[test]
+    fn test_cycles() {
+        let mut graph = diamond();
+        assert_eq!(graph.find_cycle(), None);
+
+        graph.add_edge("e", "b", ());
+        let cycle = graph.find_cycle().unwrap();
+        assert_eq!(names(&graph, &cycle.0), ["b", "d", "e"]);
+        assert_eq!(graph.topological_sort(), Err(cycle.clone()));
+        assert_eq!(
+            graph
+                .find_cycle_from(graph.id("c").unwrap())
+                .unwrap()
+                .0
+                .len(),
+            3
+        );
+        assert_eq!(graph.find_cycle_from(graph.id("f").unwrap()), None);
+        assert_eq!(
+            graph.count_paths(graph.id("a").unwrap(), graph.id("e").unwrap()),
+            Err(GraphError::Cycle(cycle))
+        );
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let graph = diamond();
+        let id = |name| graph.id(name).unwrap();
+
+        assert_eq!(graph.count_paths(id("a"), id("e")), Ok(2));
+        assert_eq!(graph.count_paths(id("b"), id("e")), Ok(1));
+        assert_eq!(graph.count_paths(id("e"), id("a")), Ok(0));
+        assert_eq!(graph.count_paths_to(id("d")).unwrap(), [2, 1, 1, 1, 0, 0]);
+
+        // 130 diamonds in a row have 2^130 paths
+        let mut chain = Graph::<u32>::new();
+        for step in 0..130 {
+            chain.add_edge(2 * step, 2 * step + 1, ());
+            chain.add_edge(2 * step, 2 * step + 2, ());
+            chain.add_edge(2 * step + 1, 2 * step + 2, ());
+        }
+        let (start, end) = (chain.id(&0).unwrap(), chain.id(&260).unwrap());
+        assert_eq!(chain.count_paths(start, end), Err(GraphError::Overflow));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = Graph::new();
+        for (from, to, weight) in &[("a", "b", 7), ("a", "c", 2), ("c", "b", 3), ("b", "d", 1)] {
+            graph.add_edge(*from, *to, *weight);
+        }
+        graph.add_node("e");
+        let id = |name| graph.id(name).unwrap();
+
+        let path = graph.shortest_path(id("a"), id("d")).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(
+            path.nodes
+                .iter()
+                .map(|id| *graph.node(*id))
+                .collect::<Vec<_>>(),
+            ["a", "c", "b", "d"]
+        );
+        assert_eq!(graph.shortest_path(id("a"), id("a")).unwrap().cost, 0);
+        assert_eq!(graph.shortest_path(id("a"), id("e")), None);
+    }
+
+    #[test]
+    fn test_a_star() {
+        // A 10x10 grid with a wall at x = 5 that is open at y = 9
+        let mut grid = Graph::new();
+        for x in 0..10i32 {
+            for y in 0..10i32 {
+                for (dx, dy) in &[(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    let (nx, ny) = (x + dx, y + dy);
+                    let wall = |x, y| x == 5 && y != 9;
+                    if (0..10).contains(&nx)
+                        && (0..10).contains(&ny)
+                        && !wall(x, y)
+                        && !wall(nx, ny)
+                    {
+                        grid.add_edge((x, y), (nx, ny), 1u32);
+                    }
+                }
+            }
+        }
+
+        let (from, to) = (grid.id(&(0, 0)).unwrap(), grid.id(&(9, 0)).unwrap());
+        let manhattan = |id| {
+            let (x, y) = *grid.node(id);
+            ((9 - x).abs() + y.abs()) as u32
+        };
+        let path = grid.a_star(from, to, manhattan).unwrap();
+
+        assert_eq!(path.cost, 27);
+        assert_eq!(path.nodes.len(), 28);
+        assert_eq!(grid.shortest_path(from, to).unwrap().cost, 27);
+    }
+}
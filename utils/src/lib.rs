@@ -1,3 +1,13 @@
+pub mod error;
+pub mod examples;
+pub mod graph;
+pub mod memo;
+pub mod number_theory;
+pub mod parse;
+mod solution;
 mod vector;
+mod vector_n;
 
+pub use solution::{read_input, run, Solution};
 pub use vector::Vector;
+pub use vector_n::VectorN;
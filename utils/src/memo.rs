@@ -0,0 +1,144 @@
+//! Caching for recursive solutions. The function gets the [`Memo`] back and
+//! recurses through [`Memo::get`], so each argument is only computed once.
+//! Calls still nest, so a long chain of keys that each need the next one can
+//! overflow the stack, where an iterative solution would not.
+//!
+//! ```
+//! use utils::memo::Memo;
+//!
+//! let fibonacci = |memo: &mut Memo<u64, u64, ()>, n: u64| -> Result<u64, ()> {
+//!     Ok(if n < 2 { n } else { memo.get(n - 1)? + memo.get(n - 2)? })
+//! };
+//! assert_eq!(Memo::new(&fibonacci).get(90), Ok(2880067194370816120));
+//! ```
+
+use std::{collections::HashMap, hash::Hash};
+
+type Function<'a, K, V, E> = dyn Fn(&mut Memo<'a, K, V, E>, K) -> Result<V, E> + 'a;
+
+/// A recursive function from `K` to `V` that fails with `E`, and the results
+/// so far
+pub struct Memo<'a, K, V, E> {
+    function: &'a Function<'a, K, V, E>,
+    /// Turns the calls from a key to the same key again into an error
+    on_cycle: Option<&'a (dyn Fn(Vec<K>) -> E + 'a)>,
+    cache: HashMap<K, V>,
+    /// The keys being computed, outermost first
+    stack: Vec<K>,
+}
+
+impl<'a, K: Eq + Hash + Clone, V: Clone, E> Memo<'a, K, V, E> {
+    /// Without cycle detection, a key that needs itself recurses until the
+    /// stack overflows, like a plain recursive function
+    pub fn new(function: &'a Function<'a, K, V, E>) -> Self {
+        Memo {
+            function,
+            on_cycle: None,
+            cache: HashMap::new(),
+            stack: vec![],
+        }
+    }
+
+    /// Fails with `on_cycle` when a key needs itself, with the keys from the
+    /// first call to the repeated one, like `[a, b, a]`
+    pub fn detect_cycles(mut self, on_cycle: &'a (dyn Fn(Vec<K>) -> E + 'a)) -> Self {
+        self.on_cycle = Some(on_cycle);
+        self
+    }
+
+    /// The result for `key`, computed at most once
+    pub fn get(&mut self, key: K) -> Result<V, E> {
+        if let Some(value) = self.cache.get(&key) {
+            return Ok(value.clone());
+        }
+
+        if let Some(on_cycle) = self.on_cycle {
+            if let Some(at) = self.stack.iter().position(|active| *active == key) {
+                let mut calls = self.stack[at..].to_vec();
+                calls.push(key);
+                return Err(on_cycle(calls));
+            }
+            self.stack.push(key.clone());
+        }
+
+        let function = self.function;
+        let result = function(self, key.clone());
+        if self.on_cycle.is_some() {
+            self.stack.pop();
+        }
+
+        let value = result?;
+        self.cache.insert(key, value.clone());
+        Ok(value)
+    }
+
+    /// The result for `key` if it has been computed
+    pub fn cached(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn test_computes_each_key_once() {
+        let calls = Cell::new(0);
+        let fibonacci = |memo: &mut Memo<u64, u64, ()>, n: u64| {
+            calls.set(calls.get() + 1);
+            Ok(if n < 2 {
+                n
+            } else {
+                memo.get(n - 1)? + memo.get(n - 2)?
+            })
+        };
+        let mut memo = Memo::new(&fibonacci);
+
+        assert_eq!(memo.get(90), Ok(2880067194370816120));
+        assert_eq!(calls.get(), 91);
+        assert_eq!(memo.get(50), Ok(12586269025));
+        assert_eq!(calls.get(), 91);
+        assert_eq!(memo.cached(&10), Some(&55));
+        assert_eq!(memo.cached(&91), None);
+    }
+
+    #[test]
+    fn test_errors_are_not_cached() {
+        let halve = |memo: &mut Memo<u32, u32, String>, n: u32| match n {
+            0 => Ok(0),
+            _ if n % 2 == 1 => Err(format!("{} is odd", n)),
+            _ => Ok(memo.get(n / 2)? + 1),
+        };
+        let mut memo = Memo::new(&halve);
+
+        assert_eq!(memo.get(12), Err("3 is odd".to_owned()));
+        assert_eq!(memo.cached(&12), None);
+        assert_eq!(memo.get(16), Err("1 is odd".to_owned()));
+        assert_eq!(memo.get(0), Ok(0));
+    }
+
+    #[test]
+    fn test_cycles() {
+        // 1 -> 2 -> 3 -> 1, and 4 -> 5
+        let next = |n: u32| match n {
+            1 => Some(2),
+            2 => Some(3),
+            3 => Some(1),
+            4 => Some(5),
+            _ => None,
+        };
+        let length = |memo: &mut Memo<u32, u32, Vec<u32>>, n: u32| match next(n) {
+            Some(next) => Ok(memo.get(next)? + 1),
+            None => Ok(0),
+        };
+        let on_cycle = |calls| calls;
+        let mut memo = Memo::new(&length).detect_cycles(&on_cycle);
+
+        assert_eq!(memo.get(2), Err(vec![2, 3, 1, 2]));
+        assert_eq!(memo.get(4), Ok(1));
+        assert_eq!(memo.get(3), Err(vec![3, 1, 2, 3]));
+    }
+}
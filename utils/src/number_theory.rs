@@ -0,0 +1,158 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtError {
+    /// The congruences contradict each other
+    NoSolution,
+    /// A modulus was zero or negative
+    InvalidModulus(i128),
+    /// An intermediate value did not fit in an i128
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::NoSolution => write!(f, "The congruences have no common solution"),
+            CrtError::InvalidModulus(modulus) => write!(f, "Invalid modulus {}", modulus),
+            CrtError::Overflow => write!(f, "Overflow while solving the congruences"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Returns `(g, x, y)` where `g = gcd(a, b) >= 0` and `a * x + b * y = g`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+pub fn gcd(a: i128, b: i128) -> i128 {
+    extended_gcd(a, b).0
+}
+
+/// The least common multiple, or `None` if it overflows
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b).map(i128::abs)
+}
+
+/// The `x` in `0..modulus` where `a * x ≡ 1 (mod modulus)`, if it exists
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    if g == 1 {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+/// Solves the system `x ≡ remainder (mod modulus)` for every `(remainder, modulus)`.
+///
+/// The moduli do not need to be pairwise coprime. Returns `(x, period)` where
+/// `period` is the least common multiple of the moduli and `x` is the smallest
+/// non-negative solution; every solution is `x + k * period`.
+pub fn crt(congruences: &[(i128, i128)]) -> Result<(i128, i128), CrtError> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, period), &(remainder, modulus)| {
+            if modulus <= 0 {
+                return Err(CrtError::InvalidModulus(modulus));
+            }
+            merge(x, period, remainder.rem_euclid(modulus), modulus)
+        })
+}
+
+/// Combines `x ≡ a1 (mod m1)` and `x ≡ a2 (mod m2)` into a single congruence
+fn merge(a1: i128, m1: i128, a2: i128, m2: i128) -> Result<(i128, i128), CrtError> {
+    let (g, _, _) = extended_gcd(m1, m2);
+    let diff = a2 - a1;
+    if diff % g != 0 {
+        return Err(CrtError::NoSolution);
+    }
+
+    let reduced_m2 = m2 / g;
+    let inverse = mod_inverse(m1 / g, reduced_m2).ok_or(CrtError::NoSolution)?;
+
+    // Find k where a1 + m1 * k ≡ a2 (mod m2)
+    let k = mul_mod((diff / g).rem_euclid(reduced_m2), inverse, reduced_m2)?;
+    let period = (m1 / g).checked_mul(m2).ok_or(CrtError::Overflow)?;
+    let x = m1
+        .checked_mul(k)
+        .and_then(|offset| offset.checked_add(a1))
+        .ok_or(CrtError::Overflow)?;
+
+    Ok((x.rem_euclid(period), period))
+}
+
+fn mul_mod(a: i128, b: i128, modulus: i128) -> Result<i128, CrtError> {
+    a.checked_mul(b)
+        .map(|product| product.rem_euclid(modulus))
+        .ok_or(CrtError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        let (g, x, y) = extended_gcd(-12, 18);
+        assert_eq!(g, 6);
+        assert_eq!(-12 * x + 18 * y, 6);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(i128::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn test_crt_coprime() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+    }
+
+    #[test]
+    fn test_crt_not_coprime() {
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Ok((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), Err(CrtError::NoSolution));
+    }
+
+    #[test]
+    fn test_crt_invalid() {
+        assert_eq!(crt(&[]), Ok((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), Err(CrtError::InvalidModulus(0)));
+    }
+}
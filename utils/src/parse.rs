@@ -0,0 +1,316 @@
+//! A small cursor over the puzzle input. The single-token methods either
+//! consume what they matched, or leave the parser where it was and return a
+//! [`ParseError`] pointing at the first character they could not use.
+
+use std::{any::type_name, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Starting at 1
+    pub line: usize,
+    /// Starting at 1, counted in characters
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    rest: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Parser {
+            rest: input,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    /// The line the parser is at, starting at 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column the parser is at, starting at 1
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    /// An error at the current position, describing what comes next as found
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = match self.peek() {
+            None => "end of input".to_owned(),
+            Some('\n') => "end of line".to_owned(),
+            Some(ch) if ch.is_whitespace() => format!("{:?}", ch),
+            Some(_) => format!(
+                "{:?}",
+                self.rest
+                    .split(char::is_whitespace)
+                    .next()
+                    .unwrap_or_default()
+            ),
+        };
+
+        ParseError {
+            line: self.line,
+            column: self.column,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// Consumes the first `len` bytes
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        for ch in taken.chars() {
+            if ch == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.rest = rest;
+
+        taken
+    }
+
+    /// Consumes characters as long as they match, possibly none
+    pub fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+        let len = self
+            .rest
+            .find(|ch| !predicate(ch))
+            .unwrap_or(self.rest.len());
+        self.advance(len)
+    }
+
+    /// Like [`Parser::take_while`], but at least one character must match
+    pub fn take_while1(
+        &mut self,
+        expected: &str,
+        predicate: impl FnMut(char) -> bool,
+    ) -> Result<&'a str, ParseError> {
+        let start = self.clone();
+        let taken = self.take_while(predicate);
+        if taken.is_empty() {
+            return Err(start.error(expected));
+        }
+
+        Ok(taken)
+    }
+
+    /// Consumes one character matching the predicate
+    pub fn satisfy(
+        &mut self,
+        expected: &str,
+        predicate: impl FnOnce(char) -> bool,
+    ) -> Result<char, ParseError> {
+        match self.peek() {
+            Some(ch) if predicate(ch) => {
+                self.advance(ch.len_utf8());
+                Ok(ch)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    /// Consumes any one character
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        self.satisfy("a character", |_| true)
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.try_literal(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("{:?}", literal)))
+        }
+    }
+
+    /// Consumes `literal` if it comes next, and tells whether it did
+    pub fn try_literal(&mut self, literal: &str) -> bool {
+        if self.rest.starts_with(literal) {
+            self.advance(literal.len());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skips spaces and tabs, but not newlines
+    pub fn spaces(&mut self) {
+        self.take_while(|ch| ch == ' ' || ch == '\t');
+    }
+
+    /// One or more ASCII letters
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.take_while1("a word", |ch| ch.is_ascii_alphabetic())
+    }
+
+    /// Decimal digits with an optional `+` or `-` in front
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.clone();
+        let sign = if self.peek() == Some('-') || self.peek() == Some('+') {
+            1
+        } else {
+            0
+        };
+        let digits = self.rest[sign..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign);
+        if digits == 0 {
+            return Err(start.error("an integer"));
+        }
+
+        let number = self.rest[..sign + digits]
+            .parse()
+            .map_err(|_| start.error(format!("an integer that fits in {}", type_name::<T>())))?;
+        self.advance(sign + digits);
+
+        Ok(number)
+    }
+
+    /// One or more items with `separator` between them
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.try_literal(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of input"))
+        }
+    }
+}
+
+/// Parses every line that is not blank with `parse_line`, which must use up
+/// the whole line
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut parse_line: impl FnMut(&mut Parser<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let mut parser = Parser {
+                rest: line,
+                line: index + 1,
+                column: 1,
+            };
+            let parsed = parse_line(&mut parser)?;
+            if !parser.is_empty() {
+                return Err(parser.error("end of line"));
+            }
+
+            Ok(parsed)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer() {
+        let mut parser = Parser::new("-12+7 x");
+        assert_eq!(parser.integer::<i32>().unwrap(), -12);
+        assert_eq!(parser.integer::<u8>().unwrap(), 7);
+        assert_eq!(parser.column(), 6);
+
+        let error = Parser::new("300").integer::<u8>().unwrap_err();
+        assert_eq!(error.expected, "an integer that fits in u8");
+        assert_eq!(error.found, "\"300\"");
+        assert!(Parser::new("-").integer::<i32>().is_err());
+        assert!(Parser::new("").integer::<i32>().is_err());
+    }
+
+    #[test]
+    fn test_separated() {
+        let mut parser = Parser::new("1, 2, 3.");
+        assert_eq!(
+            parser.separated(", ", |parser| parser.integer::<u32>()),
+            Ok(vec![1, 2, 3])
+        );
+        assert_eq!(parser.rest(), ".");
+    }
+
+    #[test]
+    fn test_error_location() {
+        let mut parser = Parser::new("ab\ncd ef");
+        parser.word().unwrap();
+        parser.literal("\n").unwrap();
+        parser.word().unwrap();
+
+        assert_eq!(
+            parser.literal(":"),
+            Err(ParseError {
+                line: 2,
+                column: 3,
+                expected: "\":\"".to_owned(),
+                found: "' '".to_owned(),
+            })
+        );
+        parser.spaces();
+        assert_eq!(parser.word(), Ok("ef"));
+        assert_eq!(parser.error("more").found, "end of input");
+    }
+
+    #[test]
+    fn test_lines() {
+        let parsed = lines("a1\n\nb2\n", |parser| {
+            Ok((parser.char()?, parser.integer::<u8>()?))
+        });
+        assert_eq!(parsed, Ok(vec![('a', 1), ('b', 2)]));
+
+        let error = lines("a1\n\nb2x\n", |parser| {
+            Ok((parser.char()?, parser.integer::<u8>()?))
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 3: expected end of line, found \"x\""
+        );
+    }
+}
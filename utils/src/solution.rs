@@ -0,0 +1,28 @@
+use std::{fmt::Display, fs::read_to_string};
+
+use anyhow::Result;
+
+/// A day of the calendar. The input is parsed once, and both parts are pure
+/// functions of the parsed input.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+}
+
+pub fn read_input() -> Result<String> {
+    Ok(read_to_string("input.txt")?)
+}
+
+/// Reads and parses `input.txt` once and prints both parts
+pub fn run<S: Solution>() -> Result<()> {
+    let input = S::parse(&read_input()?)?;
+    println!("part1: {}", S::part1(&input)?);
+    println!("part2: {}", S::part2(&input)?);
+
+    Ok(())
+}
@@ -1,37 +1,20 @@
-use std::ops::{Add, AddAssign, Mul};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A 2D vector where x points east and y points south.
+///
+/// Ordering is by x, then y.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Vector(pub i64, pub i64);
 
-fn sini(deg: i64) -> i64 {
-    if deg.abs() == 90 {
-        deg.signum()
-    } else if deg.abs() == 180 {
-        0
-    } else if deg == 0 {
-        0
-    } else if deg.abs() == 360 {
-        0
-    } else if deg.abs() == 270 {
-        -deg.signum()
-    } else {
-        panic!("Cannot do integer-sin of deg {}", deg)
-    }
-}
-
-fn cosi(deg: i64) -> i64 {
-    if deg.abs() == 90 {
-        0
-    } else if deg.abs() == 180 {
-        -1
-    } else if deg == 0 {
-        1
-    } else if deg.abs() == 360 {
-        1
-    } else if deg.abs() == 270 {
-        0
-    } else {
-        panic!("Cannot do integer-cos of deg {}", deg)
+/// Integer sine and cosine for multiples of 90 degrees, including negative
+/// angles and angles of more than a full turn
+fn sin_cos(deg: i64) -> Option<(i64, i64)> {
+    match deg.rem_euclid(360) {
+        0 => Some((0, 1)),
+        90 => Some((1, 0)),
+        180 => Some((0, -1)),
+        270 => Some((-1, 0)),
+        _ => None,
     }
 }
 
@@ -40,10 +23,79 @@ impl Vector {
     pub const WEST: Self = Vector(-1, 0);
     pub const EAST: Self = Vector(1, 0);
     pub const SOUTH: Self = Vector(0, 1);
-
+    pub const NORTH_EAST: Self = Vector(1, -1);
+    pub const NORTH_WEST: Self = Vector(-1, -1);
+    pub const SOUTH_EAST: Self = Vector(1, 1);
+    pub const SOUTH_WEST: Self = Vector(-1, 1);
+
+    pub const ZERO: Self = Vector(0, 0);
+
+    /// The four directions, clockwise from north
+    pub const CARDINALS: [Self; 4] = [Self::NORTH, Self::EAST, Self::SOUTH, Self::WEST];
+
+    /// All eight directions, clockwise from north
+    pub const COMPASS: [Self; 8] = [
+        Self::NORTH,
+        Self::NORTH_EAST,
+        Self::EAST,
+        Self::SOUTH_EAST,
+        Self::SOUTH,
+        Self::SOUTH_WEST,
+        Self::WEST,
+        Self::NORTH_WEST,
+    ];
+
+    /// Rotates clockwise (with y pointing south) by `deg`, which must be a
+    /// multiple of 90
     pub fn rotate(&self, deg: i64) -> Self {
+        self.try_rotate(deg)
+            .unwrap_or_else(|| panic!("Cannot rotate by {} degrees", deg))
+    }
+
+    /// Like `rotate`, but returns `None` if `deg` is not a multiple of 90
+    pub fn try_rotate(&self, deg: i64) -> Option<Self> {
         let Vector(x, y) = self;
-        Vector(x * cosi(deg) - y * sini(deg), x * sini(deg) + y * cosi(deg))
+        let (sin, cos) = sin_cos(deg)?;
+        Some(Vector(x * cos - y * sin, x * sin + y * cos))
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Vector(self.1, -self.0)
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Vector(-self.1, self.0)
+    }
+
+    /// The distance from the origin moving only along the axes
+    pub fn manhattan(&self) -> i64 {
+        self.0.abs() + self.1.abs()
+    }
+
+    /// Like `manhattan`, but `None` if the distance does not fit in an i64
+    pub fn checked_manhattan(&self) -> Option<i64> {
+        self.0.checked_abs()?.checked_add(self.1.checked_abs()?)
+    }
+
+    /// `None` if either coordinate overflows
+    pub fn checked_add(&self, other: Vector) -> Option<Self> {
+        Some(Vector(
+            self.0.checked_add(other.0)?,
+            self.1.checked_add(other.1)?,
+        ))
+    }
+
+    /// `None` if either coordinate overflows
+    pub fn checked_mul(&self, factor: i64) -> Option<Self> {
+        Some(Vector(
+            self.0.checked_mul(factor)?,
+            self.1.checked_mul(factor)?,
+        ))
+    }
+
+    /// The distance from the origin when diagonal steps are allowed
+    pub fn chebyshev(&self) -> i64 {
+        self.0.abs().max(self.1.abs())
     }
 
     pub fn x(&self) -> i64 {
@@ -64,6 +116,21 @@ impl Mul<i64> for Vector {
     }
 }
 
+impl MulAssign<i64> for Vector {
+    fn mul_assign(&mut self, rhs: i64) {
+        self.0 *= rhs;
+        self.1 *= rhs;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Vector(-self.0, -self.1)
+    }
+}
+
 impl Add<Vector> for Vector {
     type Output = Vector;
 
@@ -82,6 +149,24 @@ impl AddAssign<Vector> for Vector {
     }
 }
 
+impl Sub<Vector> for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        let Vector(x1, y1) = self;
+        let Vector(x2, y2) = rhs;
+
+        Vector(x1 - x2, y1 - y2)
+    }
+}
+
+impl SubAssign<Vector> for Vector {
+    fn sub_assign(&mut self, rhs: Vector) {
+        self.0 -= rhs.0;
+        self.1 -= rhs.1;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,8 +180,58 @@ mod tests {
     }
 
     #[test]
-    fn test_sini() {
-        assert_eq!(sini(90), 1);
-        assert_eq!(sini(-90), -1);
+    fn test_rotate_any_multiple_of_90() {
+        assert_eq!(Vector::NORTH.rotate(450), Vector::EAST);
+        assert_eq!(Vector::NORTH.rotate(-630), Vector::EAST);
+        assert_eq!(Vector::NORTH.rotate(-90), Vector::WEST);
+        assert_eq!(Vector(3, 4).rotate(720), Vector(3, 4));
+        assert_eq!(Vector::NORTH.try_rotate(45), None);
+    }
+
+    #[test]
+    fn test_turn() {
+        for (i, direction) in Vector::CARDINALS.iter().enumerate() {
+            assert_eq!(direction.turn_right(), Vector::CARDINALS[(i + 1) % 4]);
+            assert_eq!(direction.turn_left(), Vector::CARDINALS[(i + 3) % 4]);
+            assert_eq!(direction.turn_right(), direction.rotate(90));
+        }
+    }
+
+    #[test]
+    fn test_compass() {
+        for (i, direction) in Vector::COMPASS.iter().enumerate() {
+            assert_eq!(direction.chebyshev(), 1);
+            assert_eq!(direction.manhattan(), 1 + (i % 2) as i64);
+        }
+        assert_eq!(Vector::NORTH + Vector::EAST, Vector::NORTH_EAST);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut v = Vector(3, -4);
+        assert_eq!(v - Vector(1, 1), Vector(2, -5));
+        assert_eq!(-v, Vector(-3, 4));
+        v -= Vector(3, 0);
+        v *= 2;
+        assert_eq!(v, Vector(0, -8));
+        assert_eq!(Vector(3, -4).manhattan(), 7);
+        assert_eq!(Vector(3, -4).chebyshev(), 4);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(Vector(3, -4).checked_add(Vector(1, 1)), Some(Vector(4, -3)));
+        assert_eq!(Vector(i64::MAX, 0).checked_add(Vector::EAST), None);
+        assert_eq!(Vector(3, -4).checked_mul(-2), Some(Vector(-6, 8)));
+        assert_eq!(Vector(0, i64::MIN).checked_mul(-1), None);
+        assert_eq!(Vector(3, -4).checked_manhattan(), Some(7));
+        assert_eq!(Vector(i64::MAX, 1).checked_manhattan(), None);
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        assert_eq!(sin_cos(90), Some((1, 0)));
+        assert_eq!(sin_cos(-90), Some((-1, 0)));
+        assert_eq!(sin_cos(10), None);
     }
 }
@@ -0,0 +1,147 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::Vector;
+
+/// A vector with `D` integer components, for puzzles in three or more dimensions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VectorN<const D: usize>(pub [i64; D]);
+
+impl<const D: usize> VectorN<D> {
+    pub const ZERO: Self = VectorN([0; D]);
+
+    /// The distance from the origin moving only along the axes
+    pub fn manhattan(&self) -> i64 {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    /// The distance from the origin when diagonal steps are allowed
+    pub fn chebyshev(&self) -> i64 {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    /// All `3^D - 1` vectors with every component in -1..=1, except zero
+    pub fn directions() -> impl Iterator<Item = Self> {
+        let count = 3usize.pow(D as u32);
+        (0..count)
+            .map(|mut index| {
+                let mut components = [0; D];
+                for component in components.iter_mut() {
+                    *component = (index % 3) as i64 - 1;
+                    index /= 3;
+                }
+                VectorN(components)
+            })
+            .filter(|direction| *direction != Self::ZERO)
+    }
+
+    /// Every vector next to this one, including diagonally
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Self::directions().map(move |direction| self + direction)
+    }
+}
+
+impl<const D: usize> Default for VectorN<D> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Vector> for VectorN<2> {
+    fn from(Vector(x, y): Vector) -> Self {
+        VectorN([x, y])
+    }
+}
+
+impl From<VectorN<2>> for Vector {
+    fn from(VectorN([x, y]): VectorN<2>) -> Self {
+        Vector(x, y)
+    }
+}
+
+impl<const D: usize> Add for VectorN<D> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const D: usize> AddAssign for VectorN<D> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a += b;
+        }
+    }
+}
+
+impl<const D: usize> Sub for VectorN<D> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const D: usize> SubAssign for VectorN<D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const D: usize> Mul<i64> for VectorN<D> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: i64) -> Self::Output {
+        for a in self.0.iter_mut() {
+            *a *= rhs;
+        }
+        self
+    }
+}
+
+impl<const D: usize> Neg for VectorN<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = VectorN([1, 2, 3]);
+        let b = VectorN([-1, 0, 5]);
+
+        assert_eq!(a + b, VectorN([0, 2, 8]));
+        assert_eq!(a - b, VectorN([2, 2, -2]));
+        assert_eq!(-a * 2, VectorN([-2, -4, -6]));
+        assert_eq!((a - b).manhattan(), 6);
+        assert_eq!(b.chebyshev(), 5);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let neighbours = VectorN([1, 1, 1, 1]).neighbours().collect::<HashSet<_>>();
+
+        assert_eq!(neighbours.len(), 80);
+        assert!(neighbours.contains(&VectorN([0, 2, 1, 1])));
+        assert!(!neighbours.contains(&VectorN([1, 1, 1, 1])));
+        assert_eq!(VectorN::<3>::directions().count(), 26);
+    }
+
+    #[test]
+    fn test_from_vector() {
+        assert_eq!(VectorN::from(Vector::NORTH), VectorN([0, -1]));
+        assert_eq!(Vector::from(VectorN([2, 3])), Vector(2, 3));
+    }
+}